@@ -0,0 +1,619 @@
+#![allow(non_snake_case)]
+#![deny(missing_docs)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc = document_features::document_features!()]
+
+use std::collections::BTreeMap;
+
+use frost_core::serialization::{package_kind, peek_header};
+use frost_core::Ciphersuite;
+use rand_core::{CryptoRng, RngCore};
+use thiserror::Error;
+
+pub use frost_core::serialization::PackageKind;
+
+#[cfg(not(any(
+    feature = "decaf448",
+    feature = "ed25519",
+    feature = "ed448",
+    feature = "p256",
+    feature = "p384",
+    feature = "p521",
+    feature = "redjubjub",
+    feature = "redpallas",
+    feature = "ristretto255",
+    feature = "secp256k1",
+    feature = "secp256k1-tr",
+    feature = "sr25519",
+)))]
+compile_error!("at least one ciphersuite feature must be enabled");
+
+/// Defines an enum wrapping the given type of each of the enabled
+/// ciphersuites, along with the `From` conversions to it.
+macro_rules! any_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident;
+        $( #[$cfg:meta] $variant:ident => $ty:ty ),*
+    ) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        // Values of some curves (e.g. Ed448) are much larger than the others;
+        // boxing them would only move the cost to an allocation.
+        #[allow(clippy::large_enum_variant)]
+        pub enum $name {
+            $(
+                #[$cfg]
+                #[doc = concat!("A value of the `", stringify!($variant), "` ciphersuite.")]
+                $variant($ty),
+            )*
+        }
+
+        impl $name {
+            /// Returns the ciphersuite of the wrapped value.
+            pub fn ciphersuite(&self) -> CiphersuiteId {
+                match self {
+                    $( #[$cfg] Self::$variant(_) => CiphersuiteId::$variant, )*
+                }
+            }
+        }
+
+        $(
+            #[$cfg]
+            impl From<$ty> for $name {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+/// Adds `serialize()` and `deserialize()` methods to an enum defined with
+/// [`any_type!`] whose wrapped type starts with a header when serialized,
+/// so that the ciphersuite can be detected when deserializing.
+macro_rules! any_serialization {
+    (
+        $name:ident;
+        $( #[$cfg:meta] $variant:ident => $ty:ty ),*
+    ) => {
+        impl $name {
+            /// Serialize the wrapped value into a Vec. The encoding starts
+            /// with a header that identifies its ciphersuite.
+            pub fn serialize(&self) -> Result<Vec<u8>, Error> {
+                match self {
+                    $( #[$cfg] Self::$variant(value) => value.serialize().map_err(Error::$variant), )*
+                }
+            }
+
+            /// Deserialize a value from a slice of bytes, using the
+            /// ciphersuite identified by its header.
+            pub fn deserialize(bytes: &[u8]) -> Result<Self, Error> {
+                match CiphersuiteId::from_header(bytes)? {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => Ok(Self::$variant(
+                            <$ty>::deserialize(bytes).map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+/// Defines all the types of this crate for the given list of ciphersuites.
+macro_rules! ciphersuites {
+    ( $( #[$cfg:meta] $variant:ident => $krate:ident :: $suite:ident ),* $(,)? ) => {
+        /// Identifies one of the FROST ciphersuites enabled in this crate.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[non_exhaustive]
+        pub enum CiphersuiteId {
+            $(
+                #[$cfg]
+                #[doc = concat!("The `", stringify!($krate), "` ciphersuite.")]
+                $variant,
+            )*
+        }
+
+        impl CiphersuiteId {
+            /// All the ciphersuites enabled in this crate.
+            pub const ALL: &'static [CiphersuiteId] = &[ $( #[$cfg] CiphersuiteId::$variant, )* ];
+
+            /// Returns the [`Ciphersuite::ID`] of this ciphersuite.
+            pub fn id(self) -> &'static str {
+                match self {
+                    $( #[$cfg] Self::$variant => <$krate::$suite as Ciphersuite>::ID, )*
+                }
+            }
+
+            /// Returns the short 4-byte ID of this ciphersuite, as used in the
+            /// header of serialized structs.
+            pub fn short_id(self) -> [u8; 4] {
+                match self {
+                    $( #[$cfg] Self::$variant => frost_core::serialization::short_id::<$krate::$suite>(), )*
+                }
+            }
+        }
+
+        /// An error from a runtime-dispatched FROST operation.
+        #[non_exhaustive]
+        #[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+        pub enum Error {
+            /// The ciphersuite is unknown or not enabled in this crate.
+            #[error("Unknown ciphersuite.")]
+            UnknownCiphersuite,
+            /// Values from different ciphersuites were used together.
+            #[error("Values from different ciphersuites were used together.")]
+            CiphersuiteMismatch,
+            /// The encoding of a value was malformed.
+            #[error("Malformed encoding.")]
+            MalformedEncoding,
+            $(
+                #[$cfg]
+                #[doc = concat!("An error from the `", stringify!($krate), "` ciphersuite.")]
+                #[error(transparent)]
+                $variant($krate::Error),
+            )*
+        }
+
+        any_type! {
+            /// A [`frost_core::Identifier`] of any ciphersuite.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+            AnyIdentifier;
+            $( #[$cfg] $variant => $krate::Identifier ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::keys::SecretShare`] of any ciphersuite.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            AnySecretShare;
+            $( #[$cfg] $variant => $krate::keys::SecretShare ),*
+        }
+        any_serialization! {
+            AnySecretShare;
+            $( #[$cfg] $variant => $krate::keys::SecretShare ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::keys::KeyPackage`] of any ciphersuite.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            AnyKeyPackage;
+            $( #[$cfg] $variant => $krate::keys::KeyPackage ),*
+        }
+        any_serialization! {
+            AnyKeyPackage;
+            $( #[$cfg] $variant => $krate::keys::KeyPackage ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::keys::PublicKeyPackage`] of any ciphersuite.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            AnyPublicKeyPackage;
+            $( #[$cfg] $variant => $krate::keys::PublicKeyPackage ),*
+        }
+        any_serialization! {
+            AnyPublicKeyPackage;
+            $( #[$cfg] $variant => $krate::keys::PublicKeyPackage ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::round1::SigningNonces`] of any ciphersuite.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            AnySigningNonces;
+            $( #[$cfg] $variant => $krate::round1::SigningNonces ),*
+        }
+        any_serialization! {
+            AnySigningNonces;
+            $( #[$cfg] $variant => $krate::round1::SigningNonces ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::round1::SigningCommitments`] of any ciphersuite.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            AnySigningCommitments;
+            $( #[$cfg] $variant => $krate::round1::SigningCommitments ),*
+        }
+        any_serialization! {
+            AnySigningCommitments;
+            $( #[$cfg] $variant => $krate::round1::SigningCommitments ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::SigningPackage`] of any ciphersuite.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            AnySigningPackage;
+            $( #[$cfg] $variant => $krate::SigningPackage ),*
+        }
+        any_serialization! {
+            AnySigningPackage;
+            $( #[$cfg] $variant => $krate::SigningPackage ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::round2::SignatureShare`] of any ciphersuite.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            AnySignatureShare;
+            $( #[$cfg] $variant => $krate::round2::SignatureShare ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::Signature`] of any ciphersuite.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            AnySignature;
+            $( #[$cfg] $variant => $krate::Signature ),*
+        }
+
+        any_type! {
+            /// A [`frost_core::VerifyingKey`] of any ciphersuite.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            AnyVerifyingKey;
+            $( #[$cfg] $variant => $krate::VerifyingKey ),*
+        }
+
+        impl AnyIdentifier {
+            /// Serialize the identifier into a Vec.
+            pub fn serialize(&self) -> Vec<u8> {
+                match self {
+                    $( #[$cfg] Self::$variant(identifier) => identifier.serialize().as_ref().to_vec(), )*
+                }
+            }
+
+            /// Deserialize an identifier of the given ciphersuite from a slice
+            /// of bytes.
+            pub fn deserialize(ciphersuite: CiphersuiteId, bytes: &[u8]) -> Result<Self, Error> {
+                match ciphersuite {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => Ok(Self::$variant(
+                            $krate::Identifier::deserialize(&from_bytes(bytes)?)
+                                .map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+        }
+
+        impl AnySignatureShare {
+            /// Serialize the signature share into a Vec.
+            pub fn serialize(&self) -> Vec<u8> {
+                match self {
+                    $( #[$cfg] Self::$variant(share) => share.serialize().as_ref().to_vec(), )*
+                }
+            }
+
+            /// Deserialize a signature share of the given ciphersuite from a
+            /// slice of bytes.
+            pub fn deserialize(ciphersuite: CiphersuiteId, bytes: &[u8]) -> Result<Self, Error> {
+                match ciphersuite {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => Ok(Self::$variant(
+                            $krate::round2::SignatureShare::deserialize(from_bytes(bytes)?)
+                                .map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+        }
+
+        impl AnySignature {
+            /// Serialize the signature into a Vec.
+            pub fn serialize(&self) -> Vec<u8> {
+                match self {
+                    $( #[$cfg] Self::$variant(signature) => signature.serialize().as_ref().to_vec(), )*
+                }
+            }
+
+            /// Deserialize a signature of the given ciphersuite from a slice of
+            /// bytes.
+            pub fn deserialize(ciphersuite: CiphersuiteId, bytes: &[u8]) -> Result<Self, Error> {
+                match ciphersuite {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => Ok(Self::$variant(
+                            $krate::Signature::deserialize(from_bytes(bytes)?)
+                                .map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+        }
+
+        impl AnyVerifyingKey {
+            /// Serialize the verifying key into a Vec.
+            pub fn serialize(&self) -> Vec<u8> {
+                match self {
+                    $( #[$cfg] Self::$variant(key) => key.serialize().as_ref().to_vec(), )*
+                }
+            }
+
+            /// Deserialize a verifying key of the given ciphersuite from a
+            /// slice of bytes.
+            pub fn deserialize(ciphersuite: CiphersuiteId, bytes: &[u8]) -> Result<Self, Error> {
+                match ciphersuite {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => Ok(Self::$variant(
+                            $krate::VerifyingKey::deserialize(from_bytes(bytes)?)
+                                .map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+
+            /// Verify a purported `signature` over `message` made by this
+            /// verifying key.
+            pub fn verify(&self, message: &[u8], signature: &AnySignature) -> Result<(), Error> {
+                match (self, signature) {
+                    $(
+                        #[$cfg]
+                        (Self::$variant(key), AnySignature::$variant(signature)) => {
+                            key.verify(message, signature).map_err(Error::$variant)
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(Error::CiphersuiteMismatch),
+                }
+            }
+        }
+
+        impl TryFrom<AnySecretShare> for AnyKeyPackage {
+            type Error = Error;
+
+            fn try_from(secret_share: AnySecretShare) -> Result<Self, Error> {
+                match secret_share {
+                    $(
+                        #[$cfg]
+                        AnySecretShare::$variant(secret_share) => Ok(Self::$variant(
+                            secret_share.try_into().map_err(Error::$variant)?,
+                        )),
+                    )*
+                }
+            }
+        }
+
+        impl AnyKeyPackage {
+            /// Returns the identifier of the participant holding this key
+            /// package.
+            pub fn identifier(&self) -> AnyIdentifier {
+                match self {
+                    $( #[$cfg] Self::$variant(key_package) => AnyIdentifier::$variant(*key_package.identifier()), )*
+                }
+            }
+
+            /// Performed once by each participant selected for the signing
+            /// operation; see [`frost_core::round1::commit`].
+            ///
+            /// Generates the signing nonces and commitments, of the same
+            /// ciphersuite as this key package, to be used in the signing
+            /// operation.
+            pub fn commit<R: RngCore + CryptoRng>(
+                &self,
+                rng: &mut R,
+            ) -> (AnySigningNonces, AnySigningCommitments) {
+                match self {
+                    $(
+                        #[$cfg]
+                        Self::$variant(key_package) => {
+                            let (nonces, commitments) =
+                                $krate::round1::commit(key_package.signing_share(), rng);
+                            (nonces.into(), commitments.into())
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl AnyPublicKeyPackage {
+            /// Returns the group verifying key.
+            pub fn verifying_key(&self) -> AnyVerifyingKey {
+                match self {
+                    $( #[$cfg] Self::$variant(pubkeys) => AnyVerifyingKey::$variant(*pubkeys.verifying_key()), )*
+                }
+            }
+
+            /// Returns the minimum number of signers of the group, if known.
+            pub fn min_signers(&self) -> Option<u16> {
+                match self {
+                    $( #[$cfg] Self::$variant(pubkeys) => *pubkeys.min_signers(), )*
+                }
+            }
+
+            /// Checks that `signing_package` can be signed by the group; see
+            /// [`frost_core::keys::PublicKeyPackage::validate_signing_package`].
+            ///
+            /// Both arguments must belong to the same ciphersuite.
+            pub fn validate_signing_package(
+                &self,
+                signing_package: &AnySigningPackage,
+            ) -> Result<(), Error> {
+                match (self, signing_package) {
+                    $(
+                        #[$cfg]
+                        (Self::$variant(pubkeys), AnySigningPackage::$variant(signing_package)) => {
+                            pubkeys
+                                .validate_signing_package(signing_package)
+                                .map_err(Error::$variant)
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(Error::CiphersuiteMismatch),
+                }
+            }
+        }
+
+        impl AnySigningPackage {
+            /// Create a new signing package of the given ciphersuite, for
+            /// `message`. All the identifiers and commitments must belong to
+            /// that ciphersuite.
+            pub fn new(
+                ciphersuite: CiphersuiteId,
+                signing_commitments: BTreeMap<AnyIdentifier, AnySigningCommitments>,
+                message: &[u8],
+            ) -> Result<Self, Error> {
+                match ciphersuite {
+                    $(
+                        #[$cfg]
+                        CiphersuiteId::$variant => {
+                            let signing_commitments = signing_commitments
+                                .into_iter()
+                                .map(|entry| match entry {
+                                    (
+                                        AnyIdentifier::$variant(identifier),
+                                        AnySigningCommitments::$variant(commitments),
+                                    ) => Ok((identifier, commitments)),
+                                    #[allow(unreachable_patterns)]
+                                    _ => Err(Error::CiphersuiteMismatch),
+                                })
+                                .collect::<Result<BTreeMap<_, _>, _>>()?;
+                            Ok(Self::$variant($krate::SigningPackage::new(
+                                signing_commitments,
+                                message,
+                            )))
+                        }
+                    )*
+                }
+            }
+        }
+
+        /// Performed once by each participant selected for the signing
+        /// operation; see [`frost_core::round2::sign`].
+        ///
+        /// All the arguments must belong to the same ciphersuite.
+        pub fn sign(
+            signing_package: &AnySigningPackage,
+            signer_nonces: &AnySigningNonces,
+            key_package: &AnyKeyPackage,
+        ) -> Result<AnySignatureShare, Error> {
+            match (signing_package, signer_nonces, key_package) {
+                $(
+                    #[$cfg]
+                    (
+                        AnySigningPackage::$variant(signing_package),
+                        AnySigningNonces::$variant(signer_nonces),
+                        AnyKeyPackage::$variant(key_package),
+                    ) => Ok(AnySignatureShare::$variant(
+                        $krate::round2::sign(signing_package, signer_nonces, key_package)
+                            .map_err(Error::$variant)?,
+                    )),
+                )*
+                #[allow(unreachable_patterns)]
+                _ => Err(Error::CiphersuiteMismatch),
+            }
+        }
+
+        /// Aggregates the signature shares to produce a final signature that
+        /// can be verified with the group public key; see
+        /// [`frost_core::aggregate`].
+        ///
+        /// All the arguments must belong to the same ciphersuite.
+        pub fn aggregate(
+            signing_package: &AnySigningPackage,
+            signature_shares: &BTreeMap<AnyIdentifier, AnySignatureShare>,
+            pubkeys: &AnyPublicKeyPackage,
+        ) -> Result<AnySignature, Error> {
+            match (signing_package, pubkeys) {
+                $(
+                    #[$cfg]
+                    (AnySigningPackage::$variant(signing_package), AnyPublicKeyPackage::$variant(pubkeys)) => {
+                        let signature_shares = signature_shares
+                            .iter()
+                            .map(|entry| match entry {
+                                (AnyIdentifier::$variant(identifier), AnySignatureShare::$variant(share)) => {
+                                    Ok((*identifier, *share))
+                                }
+                                #[allow(unreachable_patterns)]
+                                _ => Err(Error::CiphersuiteMismatch),
+                            })
+                            .collect::<Result<BTreeMap<_, _>, _>>()?;
+                        Ok(AnySignature::$variant(
+                            $krate::aggregate(signing_package, &signature_shares, pubkeys)
+                                .map_err(Error::$variant)?,
+                        ))
+                    }
+                )*
+                #[allow(unreachable_patterns)]
+                _ => Err(Error::CiphersuiteMismatch),
+            }
+        }
+
+        /// Inspects a struct serialized with its `serialize()` method and
+        /// returns its format version, ciphersuite and kind, e.g. to route it
+        /// to the right handler before deserializing it.
+        pub fn inspect(bytes: &[u8]) -> Result<PackageInfo, Error> {
+            let header = peek_header(bytes).ok_or(Error::MalformedEncoding)?;
+            let ciphersuite =
+                CiphersuiteId::from_short_id(header.short_id).ok_or(Error::UnknownCiphersuite)?;
+            let kind = match ciphersuite {
+                $(
+                    #[$cfg]
+                    CiphersuiteId::$variant => {
+                        package_kind::<$krate::$suite>(bytes).map_err(Error::$variant)?
+                    }
+                )*
+            };
+            Ok(PackageInfo {
+                version: header.version,
+                ciphersuite,
+                kind,
+            })
+        }
+    };
+}
+
+ciphersuites! {
+    #[cfg(feature = "decaf448")] Decaf448 => frost_decaf448::Decaf448Shake256,
+    #[cfg(feature = "ed25519")] Ed25519 => frost_ed25519::Ed25519Sha512,
+    #[cfg(feature = "ed448")] Ed448 => frost_ed448::Ed448Shake256,
+    #[cfg(feature = "p256")] P256 => frost_p256::P256Sha256,
+    #[cfg(feature = "p384")] P384 => frost_p384::P384Sha384,
+    #[cfg(feature = "p521")] P521 => frost_p521::P521Sha512,
+    #[cfg(feature = "redjubjub")] RedJubjub => frost_redjubjub::JubjubBlake2b512,
+    #[cfg(feature = "redpallas")] RedPallas => frost_redpallas::PallasBlake2b512,
+    #[cfg(feature = "ristretto255")] Ristretto255 => frost_ristretto255::Ristretto255Sha512,
+    #[cfg(feature = "secp256k1")] Secp256K1 => frost_secp256k1::Secp256K1Sha256,
+    #[cfg(feature = "secp256k1-tr")] Secp256K1Tr => frost_secp256k1_tr::Secp256K1Sha256,
+    #[cfg(feature = "sr25519")] Sr25519 => frost_sr25519::Sr25519Sha512,
+}
+
+impl CiphersuiteId {
+    /// Returns the enabled ciphersuite with the given [`Ciphersuite::ID`], if
+    /// any.
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.id() == id)
+    }
+
+    /// Returns the enabled ciphersuite with the given short 4-byte ID, if any.
+    pub fn from_short_id(short_id: [u8; 4]) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.short_id() == short_id)
+    }
+
+    /// Returns the ciphersuite identified by the header at the start of a
+    /// serialized struct.
+    fn from_header(bytes: &[u8]) -> Result<Self, Error> {
+        let header = peek_header(bytes).ok_or(Error::MalformedEncoding)?;
+        Self::from_short_id(header.short_id).ok_or(Error::UnknownCiphersuite)
+    }
+}
+
+/// What [`inspect()`] found out about a serialized struct.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PackageInfo {
+    /// The format version.
+    pub version: u8,
+    /// The ciphersuite the struct belongs to.
+    pub ciphersuite: CiphersuiteId,
+    /// The kind of struct.
+    pub kind: PackageKind,
+}
+
+/// Converts `bytes` into the fixed-length serialization type `T`.
+fn from_bytes<T: TryFrom<Vec<u8>>>(bytes: &[u8]) -> Result<T, Error> {
+    bytes
+        .to_vec()
+        .try_into()
+        .map_err(|_| Error::MalformedEncoding)
+}
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+
+use frost_any::*;
+use rand::thread_rng;
+
+/// Generate key packages for `ciphersuite` with a trusted dealer, and return
+/// them along with the public key package after a serialization round trip,
+/// i.e. as a service loading them from storage would see them.
+fn keygen(ciphersuite: CiphersuiteId) -> (Vec<AnyKeyPackage>, AnyPublicKeyPackage) {
+    macro_rules! generate {
+        ($krate:ident) => {{
+            let (shares, pubkey_package) = $krate::keys::generate_with_dealer(
+                3,
+                2,
+                $krate::keys::IdentifierList::Default,
+                thread_rng(),
+            )
+            .unwrap();
+            let key_packages = shares
+                .into_values()
+                .map(|share| {
+                    let share = AnySecretShare::deserialize(&share.serialize().unwrap()).unwrap();
+                    AnyKeyPackage::try_from(share).unwrap()
+                })
+                .collect();
+            let pubkey_package =
+                AnyPublicKeyPackage::deserialize(&pubkey_package.serialize().unwrap()).unwrap();
+            (key_packages, pubkey_package)
+        }};
+    }
+
+    match ciphersuite {
+        CiphersuiteId::Decaf448 => generate!(frost_decaf448),
+        CiphersuiteId::Ed25519 => generate!(frost_ed25519),
+        CiphersuiteId::Ed448 => generate!(frost_ed448),
+        CiphersuiteId::P256 => generate!(frost_p256),
+        CiphersuiteId::P384 => generate!(frost_p384),
+        CiphersuiteId::P521 => generate!(frost_p521),
+        CiphersuiteId::RedJubjub => generate!(frost_redjubjub),
+        CiphersuiteId::RedPallas => generate!(frost_redpallas),
+        CiphersuiteId::Ristretto255 => generate!(frost_ristretto255),
+        CiphersuiteId::Secp256K1 => generate!(frost_secp256k1),
+        CiphersuiteId::Secp256K1Tr => generate!(frost_secp256k1_tr),
+        CiphersuiteId::Sr25519 => generate!(frost_sr25519),
+        _ => unreachable!(),
+    }
+}
+
+/// Sign `message` with the first two key packages, passing every message
+/// through its serialized form.
+fn sign_with(
+    ciphersuite: CiphersuiteId,
+    key_packages: &[AnyKeyPackage],
+    pubkey_package: &AnyPublicKeyPackage,
+    message: &[u8],
+) -> Result<AnySignature, Error> {
+    let mut rng = thread_rng();
+    let signers = &key_packages[..2];
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for key_package in signers {
+        let (signer_nonces, signer_commitments) = key_package.commit(&mut rng);
+        let signer_commitments =
+            AnySigningCommitments::deserialize(&signer_commitments.serialize()?)?;
+        nonces.insert(key_package.identifier(), signer_nonces);
+        commitments.insert(key_package.identifier(), signer_commitments);
+    }
+
+    let signing_package = AnySigningPackage::new(ciphersuite, commitments, message)?;
+    let signing_package = AnySigningPackage::deserialize(&signing_package.serialize()?)?;
+
+    let mut signature_shares = BTreeMap::new();
+    for key_package in signers {
+        let identifier = key_package.identifier();
+        let signature_share = sign(&signing_package, &nonces[&identifier], key_package)?;
+        let signature_share =
+            AnySignatureShare::deserialize(ciphersuite, &signature_share.serialize())?;
+        let identifier = AnyIdentifier::deserialize(ciphersuite, &identifier.serialize())?;
+        signature_shares.insert(identifier, signature_share);
+    }
+
+    aggregate(&signing_package, &signature_shares, pubkey_package)
+}
+
+#[test]
+fn check_ciphersuite_ids() {
+    for &ciphersuite in CiphersuiteId::ALL {
+        assert_eq!(CiphersuiteId::from_id(ciphersuite.id()), Some(ciphersuite));
+        assert_eq!(
+            CiphersuiteId::from_short_id(ciphersuite.short_id()),
+            Some(ciphersuite)
+        );
+    }
+    assert_eq!(CiphersuiteId::from_id("FROST-UNKNOWN-v1"), None);
+    assert_eq!(CiphersuiteId::from_short_id([0; 4]), None);
+}
+
+#[test]
+fn check_sign_with_dealer() {
+    for &ciphersuite in CiphersuiteId::ALL {
+        let (key_packages, pubkey_package) = keygen(ciphersuite);
+        assert_eq!(pubkey_package.ciphersuite(), ciphersuite);
+        assert!(key_packages.iter().all(|k| k.ciphersuite() == ciphersuite));
+
+        let message = b"message to sign";
+        let signature = sign_with(ciphersuite, &key_packages, &pubkey_package, message).unwrap();
+        let signature = AnySignature::deserialize(ciphersuite, &signature.serialize()).unwrap();
+
+        let verifying_key = pubkey_package.verifying_key();
+        let verifying_key =
+            AnyVerifyingKey::deserialize(ciphersuite, &verifying_key.serialize()).unwrap();
+        verifying_key.verify(message, &signature).unwrap();
+        assert!(matches!(
+            verifying_key.verify(b"another message", &signature),
+            Err(e) if e != Error::CiphersuiteMismatch
+        ));
+    }
+}
+
+#[test]
+fn check_mixed_ciphersuites_fail() {
+    let (ed25519_key_packages, ed25519_pubkey_package) = keygen(CiphersuiteId::Ed25519);
+    let (p256_key_packages, p256_pubkey_package) = keygen(CiphersuiteId::P256);
+
+    // Key packages of a different ciphersuite than the signing package.
+    assert_eq!(
+        sign_with(
+            CiphersuiteId::Ed25519,
+            &p256_key_packages,
+            &p256_pubkey_package,
+            b"message"
+        ),
+        Err(Error::CiphersuiteMismatch)
+    );
+
+    // Public key package of a different ciphersuite.
+    assert_eq!(
+        sign_with(
+            CiphersuiteId::Ed25519,
+            &ed25519_key_packages,
+            &p256_pubkey_package,
+            b"message"
+        ),
+        Err(Error::CiphersuiteMismatch)
+    );
+
+    let signature = sign_with(
+        CiphersuiteId::Ed25519,
+        &ed25519_key_packages,
+        &ed25519_pubkey_package,
+        b"message",
+    )
+    .unwrap();
+    assert_eq!(
+        p256_pubkey_package
+            .verifying_key()
+            .verify(b"message", &signature),
+        Err(Error::CiphersuiteMismatch)
+    );
+}
+
+#[test]
+fn check_deserialize_unknown_ciphersuite_fails() {
+    let (key_packages, _) = keygen(CiphersuiteId::Ristretto255);
+    let mut bytes = key_packages[0].serialize().unwrap();
+
+    assert_eq!(
+        AnyKeyPackage::deserialize(&bytes[..3]),
+        Err(Error::MalformedEncoding)
+    );
+
+    bytes[1..5].copy_from_slice(&[0xff; 4]);
+    assert_eq!(
+        AnyKeyPackage::deserialize(&bytes),
+        Err(Error::UnknownCiphersuite)
+    );
+}
+
+#[test]
+fn check_inspect() {
+    for &ciphersuite in CiphersuiteId::ALL {
+        let (key_packages, pubkey_package) = keygen(ciphersuite);
+        let (_, commitments) = key_packages[0].commit(&mut thread_rng());
+
+        for (bytes, kind) in [
+            (
+                key_packages[0].serialize().unwrap(),
+                PackageKind::KeyPackage,
+            ),
+            (
+                pubkey_package.serialize().unwrap(),
+                PackageKind::PublicKeyPackage,
+            ),
+            (
+                commitments.serialize().unwrap(),
+                PackageKind::SigningCommitments,
+            ),
+        ] {
+            assert_eq!(
+                inspect(&bytes),
+                Ok(PackageInfo {
+                    version: frost_core::serialization::FORMAT_VERSION,
+                    ciphersuite,
+                    kind
+                })
+            );
+        }
+    }
+
+    assert_eq!(inspect(&[0, 1]), Err(Error::MalformedEncoding));
+}
+
+#[test]
+fn check_deserialize_wrong_ciphersuite_fails() {
+    let (key_packages, _) = keygen(CiphersuiteId::P256);
+    let bytes = key_packages[0].serialize().unwrap();
+
+    assert_eq!(
+        frost_ed25519::keys::KeyPackage::deserialize(&bytes),
+        Err(frost_ed25519::Error::WrongCiphersuite)
+    );
+}
@@ -6,12 +6,17 @@
 //! at the cost of higher latency (the entire batch must complete), complexity
 //! of caller code (which must assemble a batch of signatures across
 //! work-items), and loss of the ability to easily pinpoint failing signatures.
-
-use std::iter::once;
+//!
+//! [`Verifier`] accepts the same signatures as [`Item::verify_single`], under
+//! the [`Ciphersuite::VerificationMode`] it was created with. Ciphersuites whose
+//! implementations disagree on which signatures are valid (e.g. Ed25519, with
+//! ZIP-215 and strict RFC 8032 rules) can decode items from their encodings
+//! with [`Item::from_encodings`], which accepts the signatures that
+//! [`Signature::deserialize`] rejects but some mode accepts.
 
 use rand_core::{CryptoRng, RngCore};
 
-use crate::{scalar_mul::VartimeMultiscalarMul, Ciphersuite, Element, *};
+use crate::{Ciphersuite, Element, *};
 
 /// A batch verification item.
 ///
@@ -24,6 +29,7 @@ pub struct Item<C: Ciphersuite> {
     sig: Signature<C>,
     sig_params: C::SigningParameters,
     c: Challenge<C>,
+    canonical: bool,
 }
 
 impl<'msg, C, M> From<(VerifyingKey<C>, Signature<C>, &'msg M)> for Item<C>
@@ -32,23 +38,98 @@ where
     M: AsRef<[u8]>,
 {
     fn from((vk, sig, msg): (VerifyingKey<C>, Signature<C>, &'msg M)) -> Self {
+        Self::new(vk, sig, SigningTarget::from_message(msg))
+    }
+}
+
+impl<C> Item<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new batch verification item for a signature over
+    /// `sig_target`, which includes any ciphersuite-specific
+    /// [`SigningParameters`](crate::SigningParameters).
+    pub fn new(
+        vk: VerifyingKey<C>,
+        sig: Signature<C>,
+        sig_target: impl Into<SigningTarget<C>>,
+    ) -> Self {
+        let sig_target = sig_target.into();
         // Compute c now to avoid dependency on the msg lifetime.
-        let sig_target = SigningTarget::from_message(msg);
         let c = <C>::challenge(&sig.R, &vk, &sig_target);
 
+        Self::from_parts(vk, sig, sig_target.sig_params, c, true)
+    }
+
+    /// Decode a new batch verification item from the encoded `verifying_key`
+    /// and `signature` over `sig_target`, with
+    /// [`Ciphersuite::decode_item`].
+    ///
+    /// Unless the ciphersuite has verification modes that accept them, this
+    /// returns an error for the encodings that [`VerifyingKey::deserialize`]
+    /// and [`Signature::deserialize`] reject.
+    pub fn from_encodings(
+        verifying_key: <C::Group as Group>::Serialization,
+        signature: C::SignatureSerialization,
+        sig_target: impl Into<SigningTarget<C>>,
+    ) -> Result<Self, Error<C>> {
+        <C>::decode_item(verifying_key, signature, sig_target.into())
+    }
+
+    /// Create a new batch verification item from its fields, with the challenge
+    /// `c` already computed, and whether the verifying key and `R` were
+    /// `canonical`ly encoded.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+    pub(crate) fn from_parts(
+        vk: VerifyingKey<C>,
+        sig: Signature<C>,
+        sig_params: C::SigningParameters,
+        c: Challenge<C>,
+        canonical: bool,
+    ) -> Self {
         Self {
             vk,
             sig,
-            sig_params: sig_target.sig_params,
+            sig_params,
             c,
+            canonical,
         }
     }
-}
 
-impl<C> Item<C>
-where
-    C: Ciphersuite,
-{
+    /// The verifying key of this item.
+    pub fn verifying_key(&self) -> &VerifyingKey<C> {
+        &self.vk
+    }
+
+    /// The signature of this item.
+    pub fn signature(&self) -> &Signature<C> {
+        &self.sig
+    }
+
+    /// The ciphersuite-specific signing parameters of this item.
+    pub fn sig_params(&self) -> &C::SigningParameters {
+        &self.sig_params
+    }
+
+    /// The challenge of this item, computed from the signature's `R`, the
+    /// verifying key and the signing target.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+    pub(crate) fn challenge(&self) -> &Challenge<C> {
+        &self.c
+    }
+
+    /// Whether the verifying key and `R` of this item were canonically
+    /// encoded. This is always the case unless the item was decoded with
+    /// [`Item::from_encodings`] by a ciphersuite that accepts non-canonical
+    /// encodings in some mode.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+    pub(crate) fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
     /// Perform non-batched verification of this `Item`.
     ///
     /// This is useful (in combination with `Item::clone`) for implementing
@@ -56,9 +137,17 @@ where
     /// [`VerifyingKey::verify`](crate::VerifyingKey::verify), which
     /// requires borrowing the message data, the `Item` type is unlinked
     /// from the lifetime of the message.
+    ///
+    /// This verifies under the default [`Ciphersuite::VerificationMode`], as
+    /// [`Verifier::new`] does.
     pub fn verify_single(self) -> Result<(), Error<C>> {
-        self.vk
-            .verify_prehashed(self.c, &self.sig, &self.sig_params)
+        self.verify_single_with_mode(C::VerificationMode::default())
+    }
+
+    /// Perform non-batched verification of this `Item` under `mode`, with
+    /// [`Ciphersuite::verify_item`].
+    pub fn verify_single_with_mode(self, mode: C::VerificationMode) -> Result<(), Error<C>> {
+        <C>::verify_item(mode, &self)
     }
 }
 
@@ -66,17 +155,28 @@ where
 pub struct Verifier<C: Ciphersuite> {
     /// Signature data queued for verification.
     signatures: Vec<Item<C>>,
+    /// The validation rules the signatures are verified under.
+    mode: C::VerificationMode,
 }
 
 impl<C> Verifier<C>
 where
     C: Ciphersuite,
 {
-    /// Constructs a new batch verifier.
+    /// Constructs a new batch verifier, which verifies under the default
+    /// [`Ciphersuite::VerificationMode`].
     pub fn new() -> Verifier<C> {
         Verifier::default()
     }
 
+    /// Constructs a new batch verifier, which verifies under `mode`.
+    pub fn with_mode(mode: C::VerificationMode) -> Verifier<C> {
+        Self {
+            signatures: vec![],
+            mode,
+        }
+    }
+
     /// Queues an Item for verification.
     pub fn queue<I: Into<Item<C>>>(&mut self, item: I) {
         self.signatures.push(item.into());
@@ -85,7 +185,10 @@ where
     /// Performs batch verification, returning `Ok(())` if all signatures were
     /// valid and `Err` otherwise, or if the batch is empty.
     ///
-    /// The batch verification equation is:
+    /// This uses [`Ciphersuite::verify_batch`], so that the result always
+    /// agrees with verifying each signature with
+    /// [`Item::verify_single_with_mode`] under the mode of the verifier. Unless
+    /// the ciphersuite overrides it, the batch verification equation is:
     ///
     /// h_G * -[sum(z_i * s_i)]P_G + sum(\[z_i\]R_i + [z_i * c_i]VK_i) = 0_G
     ///
@@ -97,8 +200,8 @@ where
     /// so that we can use multiscalar multiplication speedups.
     ///
     /// where for each signature i,
-    /// - VK_i is the verification key;
-    /// - R_i is the signature's R value;
+    /// - VK_i is the (effective) verification key;
+    /// - R_i is the signature's (effective) R value;
     /// - s_i is the signature's s value;
     /// - c_i is the hash of the message and other data;
     /// - z_i is a random 128-bit Scalar;
@@ -112,50 +215,65 @@ where
     ///
     /// [ps]: https://zips.z.cash/protocol/protocol.pdf#reddsabatchverify
     pub fn verify<R: RngCore + CryptoRng>(self, mut rng: R) -> Result<(), Error<C>> {
-        let n = self.signatures.len();
-
-        if n == 0 {
+        if self.signatures.is_empty() {
             return Err(Error::InvalidSignature);
         }
 
-        let mut VK_coeffs = Vec::with_capacity(n);
-        let mut VKs = Vec::with_capacity(n);
-        let mut R_coeffs = Vec::with_capacity(n);
-        let mut Rs = Vec::with_capacity(n);
-        let mut P_coeff_acc = <<C::Group as Group>::Field>::zero();
-
-        for item in self.signatures.iter() {
-            let z = item.sig.z;
-            let R = item.sig.R;
-            let vk = <C>::effective_pubkey_element(&item.vk, &item.sig_params);
-
-            let blind = <<C::Group as Group>::Field>::random(&mut rng);
-
-            let P_coeff = blind * z;
-            P_coeff_acc = P_coeff_acc - P_coeff;
-
-            R_coeffs.push(blind);
-            Rs.push(R);
+        <C>::verify_batch(self.mode, &self.signatures, &mut rng)
+    }
 
-            VK_coeffs.push(<<C::Group as Group>::Field>::zero() + (blind * item.c.0));
-            VKs.push(vk);
+    /// Performs batch verification and, if it fails, locates the invalid
+    /// signatures by recursively bisecting the batch and verifying each half
+    /// as a sub-batch, using randomness from `rng`.
+    ///
+    /// Returns `Ok(())` if all signatures were valid, or `Err` with the
+    /// indices (in the order they were queued) of the invalid signatures.
+    /// Unlike [`Verifier::verify`], an empty batch is accepted since it
+    /// contains no invalid signatures.
+    ///
+    /// This requires about `2 * k * log2(n / k)` sub-batch verifications to
+    /// locate `k` invalid signatures in a batch of `n`, which is much cheaper
+    /// than verifying every signature individually when `k` is small.
+    pub fn verify_and_locate<R: RngCore + CryptoRng>(self, mut rng: R) -> Result<(), Vec<usize>> {
+        if self.signatures.is_empty()
+            || <C>::verify_batch(self.mode, &self.signatures, &mut rng).is_ok()
+        {
+            return Ok(());
         }
 
-        let scalars = once(&P_coeff_acc)
-            .chain(VK_coeffs.iter())
-            .chain(R_coeffs.iter());
+        let mut invalid = Vec::new();
+        locate_invalid(self.mode, &self.signatures, 0, &mut rng, &mut invalid);
 
-        let basepoints = [C::Group::generator()];
-        let points = basepoints.iter().chain(VKs.iter()).chain(Rs.iter());
+        Err(invalid)
+    }
+}
 
-        let check: Element<C> =
-            VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(scalars, points);
+/// Appends to `invalid` the indices of the invalid signatures in `items`,
+/// offset by `offset`. `items` must be non-empty and must have already failed
+/// batch verification under `mode`.
+fn locate_invalid<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mode: C::VerificationMode,
+    items: &[Item<C>],
+    offset: usize,
+    rng: &mut R,
+    invalid: &mut Vec<usize>,
+) {
+    if items.len() == 1 {
+        invalid.push(offset);
+        return;
+    }
 
-        if (check * <C::Group>::cofactor()) == <C::Group>::identity() {
-            Ok(())
-        } else {
-            Err(Error::InvalidSignature)
-        }
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at(mid);
+
+    let left_is_valid = <C>::verify_batch(mode, left, rng).is_ok();
+    if !left_is_valid {
+        locate_invalid(mode, left, offset, rng, invalid);
+    }
+    // If the left half is valid then the right half must contain the invalid
+    // signatures, so there is no need to verify it.
+    if left_is_valid || <C>::verify_batch(mode, right, rng).is_err() {
+        locate_invalid(mode, right, offset + mid, rng, invalid);
     }
 }
 
@@ -164,6 +282,96 @@ where
     C: Ciphersuite,
 {
     fn default() -> Self {
-        Self { signatures: vec![] }
+        Self::with_mode(C::VerificationMode::default())
+    }
+}
+
+/// Verifies `items` with the default, "cofactored" batch verification
+/// equation described in [`Verifier::verify`], returning `Ok(())` if all
+/// signatures were valid and `Err` otherwise.
+///
+/// This is the default implementation of [`Ciphersuite::verify_batch`].
+#[cfg_attr(feature = "internals", visibility::make(pub))]
+#[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+pub(crate) fn verify_cofactored<C: Ciphersuite, R: RngCore + CryptoRng>(
+    items: &[Item<C>],
+    rng: &mut R,
+) -> Result<(), Error<C>> {
+    verify_cofactored_by(<C::Group>::cofactor(), items, rng)
+}
+
+/// Like [`verify_cofactored`], but multiplies the batch equation by
+/// `cofactor` instead of [`Group::cofactor`], for ciphersuites whose group
+/// elements can have a torsion component (e.g. when decoded with
+/// [`Item::from_encodings`]) although the group is treated as having prime
+/// order.
+#[cfg_attr(feature = "internals", visibility::make(pub))]
+#[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+pub(crate) fn verify_cofactored_by<C: Ciphersuite, R: RngCore + CryptoRng>(
+    cofactor: Scalar<C>,
+    items: &[Item<C>],
+    rng: &mut R,
+) -> Result<(), Error<C>> {
+    // Draw all the blinding factors upfront, so that they do not depend on how
+    // the batch is split into partial multiscalar multiplications below.
+    let blinds: Vec<Scalar<C>> = items
+        .iter()
+        .map(|_| <<C::Group as Group>::Field>::random(rng))
+        .collect();
+
+    // With the `parallel` feature, each chunk is computed on its own thread;
+    // the partial results are then summed up.
+    let chunk_len = parallel::chunk_len(items.len());
+    let chunks: Vec<_> = items
+        .chunks(chunk_len)
+        .zip(blinds.chunks(chunk_len))
+        .collect();
+    let check = parallel::map_ordered(&chunks, |(items, blinds)| partial_check::<C>(items, blinds))
+        .into_iter()
+        .fold(<C::Group>::identity(), |acc, partial| acc + partial);
+
+    if (check * cofactor) == <C::Group>::identity() {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
     }
 }
+
+/// Computes the part of the batch verification equation for `items`, using
+/// the given `blinds` as their random coefficients.
+fn partial_check<C: Ciphersuite>(items: &[Item<C>], blinds: &[Scalar<C>]) -> Element<C> {
+    let n = items.len();
+
+    let mut VK_coeffs = Vec::with_capacity(n);
+    let mut VKs = Vec::with_capacity(n);
+    let mut R_coeffs = Vec::with_capacity(n);
+    let mut Rs = Vec::with_capacity(n);
+    let mut P_coeff_acc = <<C::Group as Group>::Field>::zero();
+
+    for (item, blind) in items.iter().zip(blinds) {
+        let z = item.sig.z;
+        let R = <C>::effective_nonce_element(item.sig.R);
+        let vk = <C>::effective_pubkey_element(&item.vk, &item.sig_params);
+
+        let P_coeff = *blind * z;
+        P_coeff_acc = P_coeff_acc - P_coeff;
+
+        R_coeffs.push(*blind);
+        Rs.push(R);
+
+        VK_coeffs.push(<<C::Group as Group>::Field>::zero() + (*blind * item.c.0));
+        VKs.push(vk);
+    }
+
+    let mut scalars = Vec::with_capacity(1 + 2 * n);
+    scalars.push(P_coeff_acc);
+    scalars.extend(VK_coeffs);
+    scalars.extend(R_coeffs);
+
+    let mut points = Vec::with_capacity(1 + 2 * n);
+    points.push(C::Group::generator());
+    points.extend(VKs);
+    points.extend(Rs);
+
+    <C::Group>::vartime_multiscalar_mul(&scalars, &points)
+}
@@ -107,6 +107,26 @@ pub enum Error<C: Ciphersuite> {
     /// Error deserializing value.
     #[error("Error deserializing value.")]
     DeserializationError,
+    /// The serialized value belongs to a different ciphersuite.
+    #[error("The serialized value belongs to a different ciphersuite.")]
+    WrongCiphersuite,
+    /// The key derivation parameters are not supported.
+    #[error("The key derivation parameters are not supported.")]
+    InvalidKdfParams,
+    /// Decryption failed, because the password is wrong or the encrypted
+    /// value was modified.
+    #[error("Decryption failed.")]
+    DecryptionError,
+    /// The verifying shares or the verifying key do not match the commitment
+    /// of the group.
+    #[error("The verifying shares or key do not match the commitment.")]
+    InconsistentCommitment,
+    /// The commitment of the group is not known.
+    #[error("The commitment of the group is not known.")]
+    UnknownGroupCommitment,
+    /// The signing parameters are not valid for the ciphersuite.
+    #[error("Invalid signing parameters.")]
+    InvalidSigningParameters,
 }
 
 impl<C> Error<C>
@@ -155,6 +175,12 @@ where
             | Error::IncorrectNumberOfCommitments
             | Error::SerializationError
             | Error::DeserializationError
+            | Error::WrongCiphersuite
+            | Error::InvalidKdfParams
+            | Error::DecryptionError
+            | Error::InconsistentCommitment
+            | Error::UnknownGroupCommitment
+            | Error::InvalidSigningParameters
             | Error::IdentifierDerivationNotSupported => None,
         }
     }
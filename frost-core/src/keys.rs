@@ -1,8 +1,11 @@
 //! FROST keys, keygen, key shares
 #![allow(clippy::type_complexity)]
 
-use std::{
-    collections::{BTreeMap, BTreeSet, HashSet},
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{
     convert::TryFrom,
     default::Default,
     fmt::{self, Debug},
@@ -19,7 +22,7 @@ use zeroize::{DefaultIsZeroes, Zeroize};
 use crate::{
     serialization::{Deserialize, Serialize},
     Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar, SigningKey,
-    VerifyingKey,
+    SigningPackage, VerifyingKey,
 };
 
 #[cfg(feature = "serde")]
@@ -28,6 +31,7 @@ use crate::serialization::{ElementSerialization, ScalarSerialization};
 use super::compute_lagrange_coefficient;
 
 pub mod dkg;
+pub mod group;
 pub mod repairable;
 
 /// Sum the commitments from all participants in a distributed key generation
@@ -131,7 +135,7 @@ impl<C> Debug for SigningShare<C>
 where
     C: Ciphersuite,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SigningShare").field(&"<redacted>").finish()
     }
 }
@@ -261,7 +265,7 @@ where
     C: Ciphersuite,
 {
     fn from(secret: SigningShare<C>) -> VerifyingShare<C> {
-        VerifyingShare(<C::Group>::generator() * secret.0 as Scalar<C>)
+        VerifyingShare(<C::Group>::generator_mul(&secret.0))
     }
 }
 
@@ -330,7 +334,7 @@ impl<C> Debug for CoefficientCommitment<C>
 where
     C: Ciphersuite,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoefficientCommitment")
             .field(&hex::encode(self.serialize()))
             .finish()
@@ -485,7 +489,7 @@ where
     ///
     /// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#appendix-C.2-4
     pub fn verify(&self) -> Result<(VerifyingShare<C>, VerifyingKey<C>), Error<C>> {
-        let f_result = <C::Group>::generator() * self.signing_share.0;
+        let f_result = <C::Group>::generator_mul(&self.signing_share.0);
         let result = evaluate_vss(self.identifier, &self.commitment);
 
         if !(f_result == result) {
@@ -503,7 +507,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+        Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
@@ -579,6 +583,9 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
             generate_secret_shares(key, max_signers, min_signers, coefficients, identifiers)?
         }
     };
+    let commitment = secret_shares
+        .first()
+        .map(|secret_share| secret_share.commitment.clone());
     let mut verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>> = BTreeMap::new();
 
     let mut secret_shares_by_id: BTreeMap<Identifier<C>, SecretShare<C>> = BTreeMap::new();
@@ -590,14 +597,16 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
         secret_shares_by_id.insert(secret_share.identifier, secret_share);
     }
 
-    Ok((
+    C::post_generate(
         secret_shares_by_id,
         PublicKeyPackage {
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers: Some(min_signers),
+            commitment,
         },
-    ))
+    )
 }
 
 /// Evaluate the polynomial with the given coefficients (constant term first)
@@ -635,11 +644,16 @@ fn evaluate_vss<C: Ciphersuite>(
 ) -> Element<C> {
     let i = identifier;
 
-    let (_, result) = commitment.0.iter().fold(
-        (<<C::Group as Group>::Field>::one(), <C::Group>::identity()),
-        |(i_to_the_k, sum_so_far), comm_k| (i * i_to_the_k, sum_so_far + comm_k.0 * i_to_the_k),
-    );
-    result
+    let mut i_to_the_k = <<C::Group as Group>::Field>::one();
+    let mut scalars = Vec::with_capacity(commitment.0.len());
+    let mut elements = Vec::with_capacity(commitment.0.len());
+    for comm_k in commitment.0.iter() {
+        scalars.push(i_to_the_k);
+        elements.push(comm_k.0);
+        i_to_the_k = i * i_to_the_k;
+    }
+
+    <C::Group>::vartime_multiscalar_mul(&scalars, &elements)
 }
 
 /// A FROST keypair, which can be generated either by a trusted dealer or using
@@ -705,7 +719,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+        Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
@@ -759,21 +773,43 @@ pub struct PublicKeyPackage<C: Ciphersuite> {
     pub(crate) verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
     /// The joint public key for the entire group.
     pub(crate) verifying_key: VerifyingKey<C>,
+    /// The minimum number of signers (threshold) of the group, if known. It is
+    /// unknown in packages serialized before it was added.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) min_signers: Option<u16>,
+    /// The commitment to the polynomial the verifying shares were derived
+    /// from, if known. For a DKG, it is the sum of the commitments of all
+    /// participants.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) commitment: Option<VerifiableSecretSharingCommitment<C>>,
 }
 
 impl<C> PublicKeyPackage<C>
 where
     C: Ciphersuite,
 {
-    /// Create a new [`PublicKeyPackage`] instance.
+    /// Create a new [`PublicKeyPackage`] instance, with an unknown threshold.
     pub fn new(
         verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
         verifying_key: VerifyingKey<C>,
+    ) -> Self {
+        Self::new_with_min_signers(verifying_shares, verifying_key, None)
+    }
+
+    /// Create a new [`PublicKeyPackage`] instance with the threshold of the
+    /// group, if it is known, so that [`aggregate()`](crate::aggregate) can
+    /// enforce it.
+    pub fn new_with_min_signers(
+        verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+        verifying_key: VerifyingKey<C>,
+        min_signers: Option<u16>,
     ) -> Self {
         Self {
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers,
+            commitment: None,
         }
     }
 
@@ -789,10 +825,14 @@ where
             .iter()
             .map(|id| (*id, VerifyingShare::from_commitment(*id, commitment)))
             .collect();
-        Ok(PublicKeyPackage::new(
-            verifying_keys,
-            VerifyingKey::from_commitment(commitment)?,
-        ))
+        Ok(PublicKeyPackage {
+            commitment: Some(commitment.clone()),
+            ..PublicKeyPackage::new_with_min_signers(
+                verifying_keys,
+                VerifyingKey::from_commitment(commitment)?,
+                Some(commitment.0.len() as u16),
+            )
+        })
     }
 
     /// Computes the public key package given a map of participant identifiers
@@ -808,6 +848,111 @@ where
         let group_commitment = sum_commitments(&commitments)?;
         Self::from_commitment(&identifiers, &group_commitment)
     }
+
+    /// Check that `signing_package` can be signed by the group: that it has a
+    /// commitment from at least `min_signers` participants, if the threshold
+    /// is known, and that they are all participants of the group.
+    ///
+    /// Coordinators can call it before sending the signing package to the
+    /// participants; [`aggregate()`](crate::aggregate) checks the threshold
+    /// again.
+    pub fn validate_signing_package(
+        &self,
+        signing_package: &SigningPackage<C>,
+    ) -> Result<(), Error<C>> {
+        self.check_signer_count(signing_package.signing_commitments().len())?;
+        if !signing_package
+            .signing_commitments()
+            .keys()
+            .all(|identifier| self.verifying_shares.contains_key(identifier))
+        {
+            return Err(Error::UnknownIdentifier);
+        }
+        Ok(())
+    }
+
+    /// Check that the verifying shares, the verifying key and the threshold (if
+    /// known) match the commitment of the package.
+    ///
+    /// Returns [`Error::UnknownGroupCommitment`] if the commitment is not
+    /// known, e.g. for packages serialized before it was added.
+    pub fn verify(&self) -> Result<(), Error<C>> {
+        let commitment = self
+            .commitment
+            .as_ref()
+            .ok_or(Error::UnknownGroupCommitment)?;
+        if self
+            .min_signers
+            .is_some_and(|min_signers| min_signers as usize != commitment.0.len())
+        {
+            return Err(Error::InvalidMinSigners);
+        }
+        if self
+            .verifying_shares
+            .iter()
+            .any(|(identifier, verifying_share)| {
+                *verifying_share != VerifyingShare::from_commitment(*identifier, commitment)
+            })
+            || self.verifying_key != VerifyingKey::from_commitment(commitment)?
+        {
+            return Err(Error::InconsistentCommitment);
+        }
+        Ok(())
+    }
+
+    /// Check that `key_package` belongs to a participant of the group, and
+    /// that its verifying share, verifying key and threshold (if known) match
+    /// the package.
+    ///
+    /// It does not check the package against its commitment; call
+    /// [`PublicKeyPackage::verify()`] for that.
+    pub fn verify_key_package(&self, key_package: &KeyPackage<C>) -> Result<(), Error<C>> {
+        let verifying_share = self
+            .verifying_shares
+            .get(&key_package.identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        if self
+            .min_signers
+            .is_some_and(|min_signers| min_signers != key_package.min_signers)
+        {
+            return Err(Error::InvalidMinSigners);
+        }
+        if key_package.verifying_share != *verifying_share
+            || key_package.verifying_share != VerifyingShare::from(key_package.signing_share)
+            || key_package.verifying_key != self.verifying_key
+        {
+            return Err(Error::InconsistentCommitment);
+        }
+        Ok(())
+    }
+
+    /// Compute the verifying share of `identifier` from the commitment of the
+    /// package, e.g. for a participant added to the group after it was
+    /// created.
+    ///
+    /// Returns [`Error::UnknownGroupCommitment`] if the commitment is not
+    /// known.
+    pub fn derive_verifying_share(
+        &self,
+        identifier: Identifier<C>,
+    ) -> Result<VerifyingShare<C>, Error<C>> {
+        let commitment = self
+            .commitment
+            .as_ref()
+            .ok_or(Error::UnknownGroupCommitment)?;
+        Ok(VerifyingShare::from_commitment(identifier, commitment))
+    }
+
+    /// Check that `num_signers` reaches the threshold of the group, if it is
+    /// known.
+    pub(crate) fn check_signer_count(&self, num_signers: usize) -> Result<(), Error<C>> {
+        match self.min_signers {
+            Some(min_signers) if num_signers < min_signers as usize => {
+                Err(Error::IncorrectNumberOfCommitments)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -817,7 +962,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+        Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
@@ -871,7 +1016,7 @@ pub(crate) fn generate_secret_polynomial<C: Ciphersuite>(
     // Create the vector of commitments
     let commitment: Vec<_> = coefficients
         .iter()
-        .map(|c| CoefficientCommitment(<C::Group as Group>::generator() * *c))
+        .map(|c| CoefficientCommitment(<C::Group as Group>::generator_mul(c)))
         .collect();
     let commitment: VerifiableSecretSharingCommitment<C> =
         VerifiableSecretSharingCommitment(commitment);
@@ -910,7 +1055,7 @@ pub(crate) fn generate_secret_shares<C: Ciphersuite>(
     let (coefficients, commitment) =
         generate_secret_polynomial(secret, max_signers, min_signers, coefficients)?;
 
-    let identifiers_set: HashSet<_> = identifiers.iter().collect();
+    let identifiers_set: BTreeSet<_> = identifiers.iter().collect();
     if identifiers_set.len() != identifiers.len() {
         return Err(Error::DuplicatedIdentifier);
     }
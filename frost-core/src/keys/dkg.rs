@@ -30,7 +30,8 @@
 //! [Feldman's VSS]: https://www.cs.umd.edu/~gasarch/TOPICS/secretsharing/feldmanVSS.pdf
 //! [secure broadcast channel]: https://frost.zfnd.org/terminology.html#broadcast-channel
 
-use std::{collections::BTreeMap, iter};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::iter;
 
 use rand_core::{CryptoRng, RngCore};
 
@@ -94,7 +95,7 @@ pub mod round1 {
     {
         /// Serialize the struct into a Vec.
         pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-            Serialize::serialize(&self)
+            Serialize::serialize(self)
         }
 
         /// Deserialize the struct from a slice of bytes.
@@ -135,11 +136,11 @@ pub mod round1 {
         }
     }
 
-    impl<C> std::fmt::Debug for SecretPackage<C>
+    impl<C> core::fmt::Debug for SecretPackage<C>
     where
         C: Ciphersuite,
     {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             f.debug_struct("SecretPackage")
                 .field("identifier", &self.identifier)
                 .field("coefficients", &"<redacted>")
@@ -210,7 +211,7 @@ pub mod round2 {
     {
         /// Serialize the struct into a Vec.
         pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-            Serialize::serialize(&self)
+            Serialize::serialize(self)
         }
 
         /// Deserialize the struct from a slice of bytes.
@@ -239,11 +240,11 @@ pub mod round2 {
         pub(crate) max_signers: u16,
     }
 
-    impl<C> std::fmt::Debug for SecretPackage<C>
+    impl<C> core::fmt::Debug for SecretPackage<C>
     where
         C: Ciphersuite,
     {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             f.debug_struct("SecretPackage")
                 .field("identifier", &self.identifier)
                 .field("commitment", &self.commitment)
@@ -345,7 +346,7 @@ pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>
     // > c_i = H(i, Φ, g^{a_{i0}} , R_i), μ_i = k + a_{i0} · c_i, with Φ being
     // > a context string to prevent replay attacks.
     let mut k = <<C::Group as Group>::Field>::random(&mut rng);
-    let mut R_i = <C::Group>::generator() * k;
+    let mut R_i = <C::Group>::generator_mul(&k);
     k = <C>::effective_nonce_secret(k, &R_i);
     R_i = <C>::effective_nonce_element(R_i);
 
@@ -385,7 +386,13 @@ pub(crate) fn verify_proof_of_knowledge<C: Ciphersuite>(
     let phi_ell0 = <C>::effective_pubkey_element(&verifying_key, &Default::default());
     let c_ell = challenge::<C>(ell, &phi_ell0, &R_ell).ok_or(Error::DKGNotSupported)?;
 
-    if R_ell != <C::Group>::generator() * mu_ell - phi_ell0 * c_ell.0 {
+    let zero = <<C::Group as Group>::Field>::zero();
+    if R_ell
+        != <C::Group>::vartime_multiscalar_mul(
+            &[mu_ell, zero - c_ell.0],
+            &[<C::Group>::generator(), phi_ell0],
+        )
+    {
         return Err(Error::InvalidProofOfKnowledge { culprit: ell });
     }
     Ok(())
@@ -424,16 +431,23 @@ pub fn part2<C: Ciphersuite>(
         }
     }
 
-    let mut round2_packages = BTreeMap::new();
-
-    for (sender_identifier, round1_package) in round1_packages {
-        let ell = *sender_identifier;
-        // Round 1, Step 5
+    // Round 1, Step 5
+    //
+    // The proofs are independent, so they are checked up front (in parallel if
+    // the `parallel` feature is enabled).
+    let round1_packages_list: Vec<_> = round1_packages.iter().collect();
+    crate::parallel::try_map_ordered(&round1_packages_list, |(ell, round1_package)| {
         verify_proof_of_knowledge(
-            ell,
+            **ell,
             &round1_package.commitment,
             round1_package.proof_of_knowledge,
-        )?;
+        )
+    })?;
+
+    let mut round2_packages = BTreeMap::new();
+
+    for sender_identifier in round1_packages.keys() {
+        let ell = *sender_identifier;
 
         // Round 2, Step 1
         //
@@ -498,39 +512,49 @@ pub fn part3<C: Ciphersuite>(
         return Err(Error::IncorrectPackage);
     }
 
-    let mut signing_share = <<C::Group as Group>::Field>::zero();
+    // Round 2, Step 2
+    //
+    // > Each P_i verifies their shares by calculating:
+    // > g^{f_ℓ(i)} ≟ ∏^{t−1}_{k=0} φ^{i^k mod q}_{ℓk}, aborting if the
+    // > check fails.
+    //
+    // The checks are independent, so they are spread across threads if the
+    // `parallel` feature is enabled.
+    let round2_packages_list: Vec<_> = round2_packages.iter().collect();
+    let f_ell_is = crate::parallel::try_map_ordered(
+        &round2_packages_list,
+        |(sender_identifier, round2_package)| {
+            let ell = **sender_identifier;
+            let f_ell_i = round2_package.signing_share;
+
+            let commitment = &round1_packages
+                .get(&ell)
+                .ok_or(Error::PackageNotFound)?
+                .commitment;
+
+            // The verification is exactly the same as the regular SecretShare verification;
+            // however the required components are in different places.
+            // Build a temporary SecretShare so what we can call verify().
+            let secret_share = SecretShare {
+                header: Header::default(),
+                identifier: round2_secret_package.identifier,
+                signing_share: f_ell_i,
+                commitment: commitment.clone(),
+            };
 
-    for (sender_identifier, round2_package) in round2_packages {
-        // Round 2, Step 2
-        //
-        // > Each P_i verifies their shares by calculating:
-        // > g^{f_ℓ(i)} ≟ ∏^{t−1}_{k=0} φ^{i^k mod q}_{ℓk}, aborting if the
-        // > check fails.
-        let ell = *sender_identifier;
-        let f_ell_i = round2_package.signing_share;
-
-        let commitment = &round1_packages
-            .get(&ell)
-            .ok_or(Error::PackageNotFound)?
-            .commitment;
-
-        // The verification is exactly the same as the regular SecretShare verification;
-        // however the required components are in different places.
-        // Build a temporary SecretShare so what we can call verify().
-        let secret_share = SecretShare {
-            header: Header::default(),
-            identifier: round2_secret_package.identifier,
-            signing_share: f_ell_i,
-            commitment: commitment.clone(),
-        };
-
-        // Verify the share. We don't need the result.
-        let _ = secret_share.verify()?;
-
-        // Round 2, Step 3
-        //
-        // > Each P_i calculates their long-lived private signing share by computing
-        // > s_i = ∑^n_{ℓ=1} f_ℓ(i), stores s_i securely, and deletes each f_ℓ(i).
+            // Verify the share. We don't need the result.
+            let _ = secret_share.verify()?;
+
+            Ok::<_, Error<C>>(f_ell_i)
+        },
+    )?;
+
+    // Round 2, Step 3
+    //
+    // > Each P_i calculates their long-lived private signing share by computing
+    // > s_i = ∑^n_{ℓ=1} f_ℓ(i), stores s_i securely, and deletes each f_ℓ(i).
+    let mut signing_share = <<C::Group as Group>::Field>::zero();
+    for f_ell_i in f_ell_is {
         signing_share = signing_share + f_ell_i.0;
     }
 
@@ -561,5 +585,5 @@ pub fn part3<C: Ciphersuite>(
         min_signers: round2_secret_package.min_signers,
     };
 
-    Ok((key_package, public_key_package))
+    C::post_dkg(key_package, public_key_package)
 }
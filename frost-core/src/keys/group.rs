@@ -0,0 +1,249 @@
+//! Group descriptor, which describes a FROST group and its participants.
+
+use alloc::{collections::BTreeMap, string::String};
+
+use derive_getters::Getters;
+
+use crate::{Ciphersuite, Error, Header, Identifier, VerifyingKey};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+#[cfg(feature = "serialization")]
+use alloc::vec::Vec;
+
+use super::{
+    validate_num_of_signers, KeyPackage, PublicKeyPackage, VerifiableSecretSharingCommitment,
+    VerifyingShare,
+};
+
+/// A description of a FROST group: its threshold, its participants and the
+/// commitment to the polynomial their shares were derived from.
+///
+/// It can be stored alongside the [`KeyPackage`]s and the
+/// [`PublicKeyPackage`] of the group, and used to check that they are
+/// consistent with each other.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(try_from = "GroupDescriptorSerialization<C>"))]
+#[cfg_attr(feature = "serde", serde(into = "GroupDescriptorSerialization<C>"))]
+pub struct GroupDescriptor<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The minimum number of signers.
+    pub(crate) min_signers: u16,
+    /// The total number of participants.
+    pub(crate) max_signers: u16,
+    /// The human-readable name of each participant.
+    pub(crate) participants: BTreeMap<Identifier<C>, String>,
+    /// The commitment to the polynomial the participants' shares were derived
+    /// from. For a DKG, it is the sum of the commitments of all participants.
+    pub(crate) commitment: VerifiableSecretSharingCommitment<C>,
+    /// When the group was created, in seconds since the Unix epoch.
+    pub(crate) created_at: u64,
+    /// A free-form description of the group.
+    pub(crate) description: String,
+}
+
+impl<C> GroupDescriptor<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`GroupDescriptor`] instance.
+    ///
+    /// Returns an error if `participants` does not have `max_signers` items,
+    /// or if `commitment` does not have `min_signers` coefficients.
+    pub fn new(
+        min_signers: u16,
+        max_signers: u16,
+        participants: BTreeMap<Identifier<C>, String>,
+        commitment: VerifiableSecretSharingCommitment<C>,
+        created_at: u64,
+        description: String,
+    ) -> Result<Self, Error<C>> {
+        let descriptor = Self {
+            header: Header::default(),
+            min_signers,
+            max_signers,
+            participants,
+            commitment,
+            created_at,
+            description,
+        };
+        descriptor.verify()?;
+        Ok(descriptor)
+    }
+
+    /// Map each participant name to the identifier derived from it with
+    /// [`Identifier::derive()`], to be passed to [`GroupDescriptor::new()`].
+    ///
+    /// Use the same identifiers when generating the keys, e.g. with
+    /// [`IdentifierList::Custom`](super::IdentifierList::Custom).
+    pub fn derive_participants<'a>(
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<BTreeMap<Identifier<C>, String>, Error<C>> {
+        let mut participants = BTreeMap::new();
+        for name in names {
+            let identifier = Identifier::derive(name.as_bytes())?;
+            if participants.insert(identifier, name.into()).is_some() {
+                return Err(Error::DuplicatedIdentifier);
+            }
+        }
+        Ok(participants)
+    }
+
+    /// Return the identifier of the participant with the given name, if any.
+    pub fn identifier(&self, name: &str) -> Option<Identifier<C>> {
+        self.participants
+            .iter()
+            .find(|(_, participant)| *participant == name)
+            .map(|(identifier, _)| *identifier)
+    }
+
+    /// Return the verifying key of the group, computed from the commitment.
+    pub fn verifying_key(&self) -> Result<VerifyingKey<C>, Error<C>> {
+        VerifyingKey::from_commitment(&self.commitment)
+    }
+
+    /// Check that the descriptor is consistent: the threshold is valid, and
+    /// matches the number of participants and of coefficients in the
+    /// commitment.
+    ///
+    /// This is already checked by [`GroupDescriptor::new()`] and when
+    /// deserializing a descriptor.
+    pub fn verify(&self) -> Result<(), Error<C>> {
+        validate_num_of_signers(self.min_signers, self.max_signers)?;
+        if self.participants.len() != self.max_signers as usize {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        if self.commitment.0.len() != self.min_signers as usize {
+            return Err(Error::InvalidMinSigners);
+        }
+        Ok(())
+    }
+
+    /// Check that `key_package` belongs to a participant of the group, and
+    /// that its threshold, verifying share and verifying key match the
+    /// descriptor.
+    pub fn verify_key_package(&self, key_package: &KeyPackage<C>) -> Result<(), Error<C>> {
+        if !self.participants.contains_key(&key_package.identifier) {
+            return Err(Error::UnknownIdentifier);
+        }
+        if key_package.min_signers != self.min_signers {
+            return Err(Error::InvalidMinSigners);
+        }
+        if key_package.verifying_share != VerifyingShare::from(key_package.signing_share)
+            || key_package.verifying_share
+                != VerifyingShare::from_commitment(key_package.identifier, &self.commitment)
+            || key_package.verifying_key != self.verifying_key()?
+        {
+            return Err(Error::InconsistentCommitment);
+        }
+        Ok(())
+    }
+
+    /// Check that `public_key_package` has a verifying share for each
+    /// participant of the group, and that they, the verifying key, the
+    /// threshold and the commitment (if known) match the descriptor.
+    pub fn verify_public_key_package(
+        &self,
+        public_key_package: &PublicKeyPackage<C>,
+    ) -> Result<(), Error<C>> {
+        if public_key_package
+            .min_signers
+            .is_some_and(|min_signers| min_signers != self.min_signers)
+        {
+            return Err(Error::InvalidMinSigners);
+        }
+        if public_key_package
+            .commitment
+            .as_ref()
+            .is_some_and(|commitment| *commitment != self.commitment)
+        {
+            return Err(Error::InconsistentCommitment);
+        }
+        if public_key_package.verifying_shares.len() != self.participants.len() {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        for (identifier, verifying_share) in &public_key_package.verifying_shares {
+            if !self.participants.contains_key(identifier) {
+                return Err(Error::UnknownIdentifier);
+            }
+            if *verifying_share != VerifyingShare::from_commitment(*identifier, &self.commitment) {
+                return Err(Error::InconsistentCommitment);
+            }
+        }
+        if public_key_package.verifying_key != self.verifying_key()? {
+            return Err(Error::InconsistentCommitment);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> GroupDescriptor<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(self)
+    }
+
+    /// Deserialize the struct from a slice of bytes, checking it with
+    /// [`GroupDescriptor::verify()`].
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
+
+/// The `serde` representation of [`GroupDescriptor`], which is checked with
+/// [`GroupDescriptor::verify()`] when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct GroupDescriptorSerialization<C: Ciphersuite> {
+    header: Header<C>,
+    min_signers: u16,
+    max_signers: u16,
+    participants: BTreeMap<Identifier<C>, String>,
+    commitment: VerifiableSecretSharingCommitment<C>,
+    created_at: u64,
+    description: String,
+}
+
+#[cfg(feature = "serde")]
+impl<C: Ciphersuite> TryFrom<GroupDescriptorSerialization<C>> for GroupDescriptor<C> {
+    type Error = Error<C>;
+
+    fn try_from(value: GroupDescriptorSerialization<C>) -> Result<Self, Self::Error> {
+        let descriptor = Self {
+            header: value.header,
+            min_signers: value.min_signers,
+            max_signers: value.max_signers,
+            participants: value.participants,
+            commitment: value.commitment,
+            created_at: value.created_at,
+            description: value.description,
+        };
+        descriptor.verify()?;
+        Ok(descriptor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: Ciphersuite> From<GroupDescriptor<C>> for GroupDescriptorSerialization<C> {
+    fn from(value: GroupDescriptor<C>) -> Self {
+        Self {
+            header: value.header,
+            min_signers: value.min_signers,
+            max_signers: value.max_signers,
+            participants: value.participants,
+            commitment: value.commitment,
+            created_at: value.created_at,
+            description: value.description,
+        }
+    }
+}
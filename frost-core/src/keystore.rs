@@ -0,0 +1,369 @@
+//! Password-encrypted storage of secret material.
+//!
+//! [`KeyPackageStore`] and [`encrypt_signing_nonces()`] produce a versioned
+//! container that can be written to disk. The encryption key is derived from
+//! a password with Argon2id, and the contents are encrypted with
+//! XChaCha20-Poly1305. The container is laid out as:
+//!
+//! - version (u8): [`KEYSTORE_VERSION`];
+//! - ciphersuite short ID (4 bytes), see [`short_id()`];
+//! - kind (u8): the [`PackageKind`] of the stored struct;
+//! - Argon2id memory cost in KiB, time cost and parallelism (each a
+//!   big-endian u32), see [`KdfParams`];
+//! - salt (16 bytes);
+//! - nonce (24 bytes);
+//! - the encrypted contents, followed by the 16-byte authentication tag.
+//!
+//! All fields before the encrypted contents are authenticated as associated
+//! data.
+
+use alloc::vec::Vec;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305, XNonce,
+};
+use derive_getters::Getters;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage},
+    round1::SigningNonces,
+    serialization::{short_id, PackageKind},
+    Ciphersuite, Error,
+};
+
+/// The version of the keystore container layout.
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// The largest Argon2id memory cost accepted, in KiB (2 GiB, the first
+/// option recommended by RFC 9106).
+pub const MAX_KDF_M_COST: u32 = 2 * 1024 * 1024;
+/// The largest Argon2id number of passes accepted.
+pub const MAX_KDF_T_COST: u32 = 16;
+/// The largest Argon2id degree of parallelism accepted.
+pub const MAX_KDF_P_COST: u32 = 16;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+/// The length of the container fields before the encrypted contents.
+const HEADER_LEN: usize = 1 + 4 + 1 + 3 * 4 + SALT_LEN + NONCE_LEN;
+
+/// The Argon2id parameters used to derive the encryption key from the
+/// password. They are stored in the container, so they can be raised for new
+/// containers without breaking existing ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Getters)]
+pub struct KdfParams {
+    /// The memory cost, in KiB.
+    m_cost: u32,
+    /// The number of passes.
+    t_cost: u32,
+    /// The degree of parallelism.
+    p_cost: u32,
+}
+
+impl KdfParams {
+    /// Create new parameters. Encrypting returns
+    /// [`Error::InvalidKdfParams`] if Argon2id does not accept them, or if
+    /// they exceed [`MAX_KDF_M_COST`], [`MAX_KDF_T_COST`] or
+    /// [`MAX_KDF_P_COST`].
+    pub fn new(m_cost: u32, t_cost: u32, p_cost: u32) -> Self {
+        Self {
+            m_cost,
+            t_cost,
+            p_cost,
+        }
+    }
+
+    /// Check that the parameters do not exceed the maxima, so that a crafted
+    /// container can't make the key derivation exhaust memory or time.
+    fn check<C: Ciphersuite>(&self) -> Result<(), Error<C>> {
+        if self.m_cost > MAX_KDF_M_COST
+            || self.t_cost > MAX_KDF_T_COST
+            || self.p_cost > MAX_KDF_P_COST
+        {
+            return Err(Error::InvalidKdfParams);
+        }
+        Ok(())
+    }
+
+    fn argon2<C: Ciphersuite>(&self) -> Result<Argon2<'static>, Error<C>> {
+        self.check()?;
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(32))
+            .map_err(|_| Error::InvalidKdfParams)?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+impl Default for KdfParams {
+    /// The parameters recommended by RFC 9106 for memory-constrained
+    /// environments: 64 MiB of memory and 3 passes.
+    fn default() -> Self {
+        Self {
+            m_cost: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+        }
+    }
+}
+
+/// A [`KeyPackage`] to be encrypted with a password, along with the group's
+/// [`PublicKeyPackage`] and application metadata, if any.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+pub struct KeyPackageStore<C: Ciphersuite> {
+    /// The participant's key package.
+    key_package: KeyPackage<C>,
+    /// The group's public key package.
+    public_key_package: Option<PublicKeyPackage<C>>,
+    /// Application metadata, stored encrypted along with the key package.
+    metadata: Vec<u8>,
+}
+
+impl<C> KeyPackageStore<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`KeyPackageStore`] instance.
+    pub fn new(
+        key_package: KeyPackage<C>,
+        public_key_package: Option<PublicKeyPackage<C>>,
+        metadata: Vec<u8>,
+    ) -> Self {
+        Self {
+            key_package,
+            public_key_package,
+            metadata,
+        }
+    }
+
+    /// Encrypt the store with a key derived from `password`.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        &self,
+        password: &[u8],
+        params: &KdfParams,
+        rng: R,
+    ) -> Result<Vec<u8>, Error<C>> {
+        // key_package || public_key_package? || metadata, each as a
+        // length-prefixed byte string, with a presence byte before the public
+        // key package.
+        let key_package = Zeroizing::new(self.key_package.serialize()?);
+        let public_key_package = self
+            .public_key_package
+            .as_ref()
+            .map(PublicKeyPackage::serialize)
+            .transpose()?;
+        // Allocate the exact length up front, so that no reallocation leaves a
+        // copy of the signing share in freed memory.
+        let len = 4
+            + key_package.len()
+            + 1
+            + public_key_package.as_ref().map_or(0, |p| 4 + p.len())
+            + 4
+            + self.metadata.len();
+        let mut contents = Zeroizing::new(Vec::with_capacity(len));
+        write_byte_string(&mut contents, &key_package)?;
+        match &public_key_package {
+            Some(public_key_package) => {
+                contents.push(1);
+                write_byte_string(&mut contents, public_key_package)?;
+            }
+            None => contents.push(0),
+        }
+        write_byte_string(&mut contents, &self.metadata)?;
+        debug_assert_eq!(contents.len(), len);
+
+        seal(PackageKind::KeyPackage, &contents, password, params, rng)
+    }
+
+    /// Decrypt a store encrypted with [`KeyPackageStore::encrypt()`].
+    ///
+    /// Returns [`Error::WrongCiphersuite`] if it belongs to another
+    /// ciphersuite, and [`Error::DecryptionError`] if the password is wrong or
+    /// the container was modified.
+    pub fn decrypt(bytes: &[u8], password: &[u8]) -> Result<Self, Error<C>> {
+        let contents = open::<C>(PackageKind::KeyPackage, bytes, password)?;
+        let mut rest = contents.as_slice();
+
+        let key_package = KeyPackage::deserialize(read_byte_string(&mut rest)?)?;
+        let public_key_package = match read_byte(&mut rest)? {
+            0 => None,
+            1 => Some(PublicKeyPackage::deserialize(read_byte_string(&mut rest)?)?),
+            _ => return Err(Error::DeserializationError),
+        };
+        let metadata = read_byte_string(&mut rest)?.to_vec();
+        if !rest.is_empty() {
+            return Err(Error::DeserializationError);
+        }
+
+        Ok(Self {
+            key_package,
+            public_key_package,
+            metadata,
+        })
+    }
+}
+
+/// Encrypt `signing_nonces` with a key derived from `password`, e.g. to keep
+/// them on disk between the two signing rounds.
+///
+/// The nonces must still be used only once: decrypting the same container
+/// twice gives the same nonces, so the caller must delete it once they are
+/// used.
+pub fn encrypt_signing_nonces<C: Ciphersuite, R: RngCore + CryptoRng>(
+    signing_nonces: &SigningNonces<C>,
+    password: &[u8],
+    params: &KdfParams,
+    rng: R,
+) -> Result<Vec<u8>, Error<C>> {
+    let contents = Zeroizing::new(signing_nonces.serialize()?);
+    seal(PackageKind::SigningNonces, &contents, password, params, rng)
+}
+
+/// Decrypt nonces encrypted with [`encrypt_signing_nonces()`].
+///
+/// Returns [`Error::WrongCiphersuite`] if they belong to another ciphersuite,
+/// and [`Error::DecryptionError`] if the password is wrong or the container
+/// was modified.
+pub fn decrypt_signing_nonces<C: Ciphersuite>(
+    bytes: &[u8],
+    password: &[u8],
+) -> Result<SigningNonces<C>, Error<C>> {
+    let contents = open::<C>(PackageKind::SigningNonces, bytes, password)?;
+    SigningNonces::deserialize(&contents)
+}
+
+/// Encrypt `contents` into a container holding a struct of the given kind.
+fn seal<C: Ciphersuite, R: RngCore + CryptoRng>(
+    kind: PackageKind,
+    contents: &[u8],
+    password: &[u8],
+    params: &KdfParams,
+    mut rng: R,
+) -> Result<Vec<u8>, Error<C>> {
+    let mut salt = [0u8; SALT_LEN];
+    rng.fill_bytes(&mut salt);
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    header.push(KEYSTORE_VERSION);
+    header.extend_from_slice(&short_id::<C>());
+    header.push(kind as u8);
+    header.extend_from_slice(&params.m_cost.to_be_bytes());
+    header.extend_from_slice(&params.t_cost.to_be_bytes());
+    header.extend_from_slice(&params.p_cost.to_be_bytes());
+    header.extend_from_slice(&salt);
+    header.extend_from_slice(&nonce);
+
+    let cipher = cipher(password, params, &salt)?;
+    let ciphertext = cipher
+        .encrypt(
+            &XNonce::from(nonce),
+            Payload {
+                msg: contents,
+                aad: &header,
+            },
+        )
+        .map_err(|_| Error::SerializationError)?;
+
+    header.extend_from_slice(&ciphertext);
+    Ok(header)
+}
+
+/// Decrypt the contents of a container, which must hold a struct of the given
+/// kind.
+fn open<C: Ciphersuite>(
+    kind: PackageKind,
+    bytes: &[u8],
+    password: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+    let (header, ciphertext) = bytes
+        .split_at_checked(HEADER_LEN)
+        .ok_or(Error::DeserializationError)?;
+    let mut rest = header;
+
+    let version = read_byte(&mut rest)?;
+    let suite: [u8; 4] = read_array(&mut rest)?;
+    let stored_kind = read_byte(&mut rest)?;
+    if version != KEYSTORE_VERSION {
+        return Err(Error::DeserializationError);
+    }
+    // Checked before deriving the key, so that a container of another
+    // ciphersuite is refused even with the right password.
+    if suite != short_id::<C>() {
+        return Err(Error::WrongCiphersuite);
+    }
+    if stored_kind != kind as u8 {
+        return Err(Error::DeserializationError);
+    }
+
+    let params = KdfParams::new(
+        u32::from_be_bytes(read_array(&mut rest)?),
+        u32::from_be_bytes(read_array(&mut rest)?),
+        u32::from_be_bytes(read_array(&mut rest)?),
+    );
+    // The parameters are only authenticated once the key is derived, so they
+    // must be bounded before deriving it.
+    params.check()?;
+    let salt: [u8; SALT_LEN] = read_array(&mut rest)?;
+    let nonce: [u8; NONCE_LEN] = read_array(&mut rest)?;
+
+    let cipher = cipher(password, &params, &salt)?;
+    let contents = cipher
+        .decrypt(
+            &XNonce::from(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| Error::DecryptionError)?;
+    Ok(Zeroizing::new(contents))
+}
+
+/// Derive the encryption key from `password` and build the cipher.
+fn cipher<C: Ciphersuite>(
+    password: &[u8],
+    params: &KdfParams,
+    salt: &[u8],
+) -> Result<XChaCha20Poly1305, Error<C>> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    params
+        .argon2()?
+        .hash_password_into(password, salt, key.as_mut())
+        .map_err(|_| Error::InvalidKdfParams)?;
+    XChaCha20Poly1305::new_from_slice(key.as_ref()).map_err(|_| Error::InvalidKdfParams)
+}
+
+fn write_byte_string<C: Ciphersuite>(buffer: &mut Vec<u8>, bytes: &[u8]) -> Result<(), Error<C>> {
+    let len = u32::try_from(bytes.len()).map_err(|_| Error::SerializationError)?;
+    buffer.extend_from_slice(&len.to_be_bytes());
+    buffer.extend_from_slice(bytes);
+    Ok(())
+}
+
+fn read_byte<C: Ciphersuite>(bytes: &mut &[u8]) -> Result<u8, Error<C>> {
+    let (&byte, rest) = bytes.split_first().ok_or(Error::DeserializationError)?;
+    *bytes = rest;
+    Ok(byte)
+}
+
+fn read_array<C: Ciphersuite, const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], Error<C>> {
+    let (array, rest) = bytes
+        .split_first_chunk::<N>()
+        .ok_or(Error::DeserializationError)?;
+    *bytes = rest;
+    Ok(*array)
+}
+
+fn read_byte_string<'a, C: Ciphersuite>(bytes: &mut &'a [u8]) -> Result<&'a [u8], Error<C>> {
+    let len = u32::from_be_bytes(read_array(bytes)?);
+    let len = usize::try_from(len).map_err(|_| Error::DeserializationError)?;
+    let (string, rest) = bytes
+        .split_at_checked(len)
+        .ok_or(Error::DeserializationError)?;
+    *bytes = rest;
+    Ok(string)
+}
@@ -9,9 +9,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::{
+#[macro_use]
+extern crate alloc;
+
+use alloc::{
     collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{
     default::Default,
     fmt::{self, Debug},
     marker::PhantomData,
@@ -29,6 +36,9 @@ pub mod benches;
 mod error;
 mod identifier;
 pub mod keys;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+mod parallel;
 pub mod round1;
 pub mod round2;
 mod scalar_mul;
@@ -45,7 +55,7 @@ mod verifying_key;
 
 pub use error::{Error, FieldError, GroupError};
 pub use identifier::Identifier;
-use scalar_mul::VartimeMultiscalarMul;
+pub use scalar_mul::GeneratorTable;
 // Re-export serde
 #[cfg(feature = "serde")]
 pub use serde;
@@ -85,7 +95,7 @@ impl<C> Debug for Challenge<C>
 where
     C: Ciphersuite,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Secret")
             .field(&hex::encode(<<C::Group as Group>::Field>::serialize(
                 &self.0,
@@ -379,6 +389,15 @@ impl<C: Ciphersuite> SigningTarget<C> {
             sig_params: C::SigningParameters::default(),
         }
     }
+
+    /// Check that the message can be signed with the signing parameters, see
+    /// [`SigningParameters::accepts_message()`](crate::SigningParameters::accepts_message).
+    pub(crate) fn check(&self) -> Result<(), Error<C>> {
+        if !self.sig_params.accepts_message(&self.message) {
+            return Err(Error::InvalidSigningParameters);
+        }
+        Ok(())
+    }
 }
 
 impl<C: Ciphersuite, T: AsRef<[u8]>> From<T> for SigningTarget<C> {
@@ -417,6 +436,10 @@ where
     /// Some ciphersuites like `frost-secp256k1-tr` allow customization of the signing
     /// process by embedding additional parameters into a [`SigningTarget`], but this
     /// is optional and not required by most ciphersuites.
+    ///
+    /// If the signing parameters do not accept the message, e.g. if they select
+    /// a prehash variant and the message is not a prehash, [`round2::sign()`]
+    /// and [`aggregate()`] return [`Error::InvalidSigningParameters`].
     pub fn new(
         signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
         sig_target: impl Into<SigningTarget<C>>,
@@ -486,7 +509,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        serialization::Serialize::serialize(&self)
+        serialization::Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
@@ -564,7 +587,7 @@ where
     }
 
     let accumulated_binding_commitment: Element<C> =
-        VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(binding_scalars, binding_elements);
+        <C::Group>::vartime_multiscalar_mul(&binding_scalars, &binding_elements);
 
     group_commitment = group_commitment + accumulated_binding_commitment;
 
@@ -607,6 +630,8 @@ where
     if signing_package.signing_commitments().len() != signature_shares.len() {
         return Err(Error::UnknownIdentifier);
     }
+    pubkeys.check_signer_count(signing_package.signing_commitments().len())?;
+    signing_package.sig_target.check()?;
     if !signing_package.signing_commitments().keys().all(|id| {
         #[cfg(feature = "cheater-detection")]
         return signature_shares.contains_key(id) && pubkeys.verifying_shares().contains_key(id);
@@ -653,40 +678,47 @@ where
         // Compute the per-message challenge.
         let challenge = <C>::challenge(&R, &pubkeys.verifying_key, &signing_package.sig_target);
 
-        // Verify the signature shares.
-        for (signature_share_identifier, signature_share) in signature_shares {
-            // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
-            // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
-            let signer_pubkey = pubkeys
-                .verifying_shares
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
-
-            // Compute Lagrange coefficient.
-            let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
-
-            let binding_factor = binding_factor_list
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
-
-            // Compute the commitment share.
-            let R_share = signing_package
-                .signing_commitment(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?
-                .to_group_commitment_share(binding_factor);
-
-            // Compute relation values to verify this signature share.
-            signature_share.verify(
-                *signature_share_identifier,
-                &R_share,
-                signer_pubkey,
-                lambda_i,
-                &challenge,
-                &group_commitment,
-                &pubkeys.verifying_key,
-                &signing_package.sig_target.sig_params,
-            )?;
-        }
+        // Verify the signature shares. With the `parallel` feature this is
+        // spread across threads; the error for the first cheater (in
+        // identifier order) is returned either way.
+        let signature_shares: Vec<_> = signature_shares.iter().collect();
+        parallel::try_map_ordered(
+            &signature_shares,
+            |(signature_share_identifier, signature_share)| {
+                // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
+                // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
+                let signer_pubkey = pubkeys
+                    .verifying_shares
+                    .get(signature_share_identifier)
+                    .ok_or(Error::UnknownIdentifier)?;
+
+                // Compute Lagrange coefficient.
+                let lambda_i =
+                    derive_interpolating_value(signature_share_identifier, signing_package)?;
+
+                let binding_factor = binding_factor_list
+                    .get(signature_share_identifier)
+                    .ok_or(Error::UnknownIdentifier)?;
+
+                // Compute the commitment share.
+                let R_share = signing_package
+                    .signing_commitment(signature_share_identifier)
+                    .ok_or(Error::UnknownIdentifier)?
+                    .to_group_commitment_share(binding_factor);
+
+                // Compute relation values to verify this signature share.
+                signature_share.verify(
+                    **signature_share_identifier,
+                    &R_share,
+                    signer_pubkey,
+                    lambda_i,
+                    &challenge,
+                    &group_commitment,
+                    &pubkeys.verifying_key,
+                    &signing_package.sig_target.sig_params,
+                )
+            },
+        )?;
 
         // We should never reach here; but we return the verification error to be safe.
         return Err(err);
@@ -0,0 +1,83 @@
+//! Helpers to spread independent checks across threads.
+//!
+//! With the `parallel` feature enabled these use the global [rayon] thread
+//! pool; otherwise they run sequentially. Either way, results are returned in
+//! the order of the inputs so that callers report the same error regardless
+//! of how the work was scheduled.
+//!
+//! [rayon]: https://docs.rs/rayon
+
+use alloc::vec::Vec;
+
+/// The smallest number of terms worth handing to a separate thread when
+/// splitting a multiscalar multiplication.
+#[cfg(feature = "parallel")]
+const MIN_CHUNK_LEN: usize = 32;
+
+/// Applies `f` to each of `items`, returning the results in the same order.
+#[cfg(feature = "parallel")]
+pub(crate) fn map_ordered<T, U, F>(items: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync + Send,
+{
+    use rayon::prelude::*;
+
+    items.par_iter().map(f).collect()
+}
+
+/// Applies `f` to each of `items`, returning the results in the same order.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn map_ordered<T, U, F>(items: &[T], f: F) -> Vec<U>
+where
+    F: Fn(&T) -> U,
+{
+    items.iter().map(f).collect()
+}
+
+/// Applies the fallible `f` to each of `items`, returning either all the
+/// results in order or the error for the first failing item.
+///
+/// All items are processed even if one of them fails, so this should only be
+/// used when failures are expected to be rare.
+#[cfg(feature = "parallel")]
+pub(crate) fn try_map_ordered<T, U, E, F>(items: &[T], f: F) -> Result<Vec<U>, E>
+where
+    T: Sync,
+    U: Send,
+    E: Send,
+    F: Fn(&T) -> Result<U, E> + Sync + Send,
+{
+    map_ordered(items, f).into_iter().collect()
+}
+
+/// Applies the fallible `f` to each of `items`, returning either all the
+/// results in order or the error for the first failing item.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn try_map_ordered<T, U, E, F>(items: &[T], f: F) -> Result<Vec<U>, E>
+where
+    F: Fn(&T) -> Result<U, E>,
+{
+    items.iter().map(f).collect()
+}
+
+/// Returns how many items to put in each chunk when splitting `n` items into
+/// partial multiscalar multiplications.
+///
+/// This is always at least 1, so the result can be passed to
+/// [`slice::chunks`].
+#[cfg(feature = "parallel")]
+pub(crate) fn chunk_len(n: usize) -> usize {
+    n.div_ceil(rayon::current_num_threads()).max(MIN_CHUNK_LEN)
+}
+
+/// Returns how many items to put in each chunk when splitting `n` items into
+/// partial multiscalar multiplications.
+///
+/// This is always at least 1, so the result can be passed to
+/// [`slice::chunks`].
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn chunk_len(n: usize) -> usize {
+    n.max(1)
+}
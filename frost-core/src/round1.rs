@@ -1,9 +1,7 @@
 //! FROST Round 1 functionality and types
 
-use std::{
-    collections::BTreeMap,
-    fmt::{self, Debug},
-};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::fmt::{self, Debug};
 
 use derive_getters::Getters;
 #[cfg(any(test, feature = "test-impl"))]
@@ -210,7 +208,7 @@ where
     C: Ciphersuite,
 {
     fn from(nonce: &Nonce<C>) -> Self {
-        Self(<C::Group>::generator() * nonce.0)
+        Self(<C::Group>::generator_mul(&nonce.0))
     }
 }
 
@@ -300,7 +298,7 @@ impl<C> Debug for SigningNonces<C>
 where
     C: Ciphersuite,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SigningNonces")
             .field("hiding", &"<redacted>")
             .field("binding", &"<redacted>")
@@ -315,7 +313,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+        Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
@@ -381,7 +379,7 @@ where
 {
     /// Serialize the struct into a Vec.
     pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+        Serialize::serialize(self)
     }
 
     /// Deserialize the struct from a slice of bytes.
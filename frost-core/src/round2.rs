@@ -1,6 +1,6 @@
 //! FROST Round 2 functionality and types, for signature share generation
 
-use std::fmt::{self, Debug};
+use core::fmt::{self, Debug};
 
 use crate as frost;
 use crate::{
@@ -99,7 +99,7 @@ where
             <C>::effective_commitment_share(group_commitment_share.clone(), &group_commitment);
         let vsh = <C>::effective_verifying_share(&verifying_share, &verifying_key, &sig_params);
 
-        if (<C::Group>::generator() * self.share)
+        if <C::Group>::generator_mul(&self.share)
             != (commitment_share + (vsh * challenge.0 * lambda_i))
         {
             return Err(Error::InvalidSignatureShare {
@@ -192,6 +192,7 @@ pub fn sign<C: Ciphersuite>(
     if signing_package.signing_commitments().len() < key_package.min_signers as usize {
         return Err(Error::IncorrectNumberOfCommitments);
     }
+    signing_package.sig_target.check()?;
 
     // Validate the signer's commitment is present in the signing package
     let commitment = signing_package
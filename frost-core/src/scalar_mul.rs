@@ -1,16 +1,21 @@
-//! Non-adjacent form (NAF) implementations for fast batch scalar multiplcation
+//! Non-adjacent form (NAF) implementations for fast batch scalar multiplcation,
+//! a Pippenger bucket implementation for very large batches, and a precomputed
+//! table for fast generator multiplication.
 
 // We expect slicings in this module to never panic due to algorithmic
 // constraints.
 #![allow(clippy::indexing_slicing)]
 
-use std::{
+use alloc::vec::Vec;
+use core::{
     borrow::Borrow,
     fmt::{Debug, Result},
     marker::PhantomData,
 };
 
-use crate::{Ciphersuite, Element, Field, Group, Scalar};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::{Field, Group};
 
 /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
 ///
@@ -33,14 +38,14 @@ const fn div_ceil(lhs: usize, rhs: usize) -> usize {
     }
 }
 
-/// A trait for transforming a scalar generic over a ciphersuite to a non-adjacent form (NAF).
-pub trait NonAdjacentForm<C: Ciphersuite> {
+/// A trait for transforming a scalar generic over a group to a non-adjacent form (NAF).
+pub trait NonAdjacentForm<G: Group> {
     fn non_adjacent_form(&self, w: usize) -> Vec<i8>;
 }
 
-impl<C> NonAdjacentForm<C> for Scalar<C>
+impl<G> NonAdjacentForm<G> for <G::Field as Field>::Scalar
 where
-    C: Ciphersuite,
+    G: Group,
 {
     /// Computes a width-(w) "Non-Adjacent Form" of this scalar.
     ///
@@ -57,7 +62,7 @@ where
 
         use byteorder::{ByteOrder, LittleEndian};
 
-        let serialized_scalar = <<C::Group as Group>::Field>::little_endian_serialize(self);
+        let serialized_scalar = <G::Field>::little_endian_serialize(self);
         // The canonical serialization length of this `Scalar` in bytes.
         let serialization_len = serialized_scalar.as_ref().len();
 
@@ -138,7 +143,7 @@ where
 /// A trait for variable-time multiscalar multiplication without precomputation.
 ///
 /// Implement for a group element.
-pub trait VartimeMultiscalarMul<C: Ciphersuite>: Clone {
+pub trait VartimeMultiscalarMul<G: Group>: Clone {
     /// Given an iterator of public scalars and an iterator of
     /// `Option`s of group elements, compute either `Some(Q)`, where
     /// $$
@@ -148,7 +153,7 @@ pub trait VartimeMultiscalarMul<C: Ciphersuite>: Clone {
     fn optional_multiscalar_mul<I, J>(scalars: I, elements: J) -> Option<Self>
     where
         I: IntoIterator,
-        I::Item: Borrow<Scalar<C>>,
+        I::Item: Borrow<<G::Field as Field>::Scalar>,
         J: IntoIterator<Item = Option<Self>>;
 
     /// Given an iterator of public scalars and an iterator of
@@ -162,7 +167,7 @@ pub trait VartimeMultiscalarMul<C: Ciphersuite>: Clone {
     fn vartime_multiscalar_mul<I, J>(scalars: I, elements: J) -> Self
     where
         I: IntoIterator,
-        I::Item: Borrow<Scalar<C>>,
+        I::Item: Borrow<<G::Field as Field>::Scalar>,
         J: IntoIterator,
         J::Item: Borrow<Self>,
     {
@@ -174,65 +179,283 @@ pub trait VartimeMultiscalarMul<C: Ciphersuite>: Clone {
     }
 }
 
-impl<C> VartimeMultiscalarMul<C> for Element<C>
+/// The number of terms from which [`VartimeMultiscalarMul`] switches from
+/// the Straus algorithm to the Pippenger bucket method.
+///
+/// This is the same threshold used by curve25519-dalek.
+pub(crate) const PIPPENGER_THRESHOLD: usize = 190;
+
+impl<G> VartimeMultiscalarMul<G> for G::Element
 where
-    C: Ciphersuite,
+    G: Group,
 {
-    #[allow(clippy::comparison_chain)]
-    fn optional_multiscalar_mul<I, J>(scalars: I, elements: J) -> Option<Element<C>>
+    fn optional_multiscalar_mul<I, J>(scalars: I, elements: J) -> Option<G::Element>
     where
         I: IntoIterator,
-        I::Item: Borrow<Scalar<C>>,
-        J: IntoIterator<Item = Option<Element<C>>>,
+        I::Item: Borrow<<G::Field as Field>::Scalar>,
+        J: IntoIterator<Item = Option<G::Element>>,
     {
-        let nafs: Vec<_> = scalars
-            .into_iter()
-            .map(|c| NonAdjacentForm::<C>::non_adjacent_form(c.borrow(), 5))
-            .collect();
+        let scalars: Vec<_> = scalars.into_iter().map(|c| *c.borrow()).collect();
+        let elements = elements.into_iter().collect::<Option<Vec<_>>>()?;
 
-        let lookup_tables = elements
-            .into_iter()
-            .map(|P_opt| P_opt.map(|P| LookupTable5::<C, Element<C>>::from(&P)))
-            .collect::<Option<Vec<_>>>()?;
-
-        if nafs.len() != lookup_tables.len() {
+        if scalars.len() != elements.len() {
             return None;
         }
 
-        let mut r = <C::Group>::identity();
+        if scalars.len() < PIPPENGER_THRESHOLD {
+            Some(straus_multiscalar_mul::<G>(&scalars, &elements))
+        } else {
+            Some(pippenger_multiscalar_mul::<G>(&scalars, &elements))
+        }
+    }
+}
+
+/// Computes a multiscalar multiplication using the Straus algorithm with
+/// width-5 NAFs. `scalars` and `elements` must have the same length.
+#[allow(clippy::comparison_chain)]
+pub(crate) fn straus_multiscalar_mul<G: Group>(
+    scalars: &[<G::Field as Field>::Scalar],
+    elements: &[G::Element],
+) -> G::Element {
+    debug_assert_eq!(scalars.len(), elements.len());
+
+    let nafs: Vec<_> = scalars
+        .iter()
+        .map(|c| NonAdjacentForm::<G>::non_adjacent_form(c, 5))
+        .collect();
+
+    let lookup_tables: Vec<_> = elements
+        .iter()
+        .map(LookupTable5::<G, G::Element>::from)
+        .collect();
+
+    let mut r = G::identity();
+
+    // All NAFs will have the same size, so get it from the first
+    if nafs.is_empty() {
+        return r;
+    }
+    let naf_length = nafs[0].len();
 
-        // All NAFs will have the same size, so get it from the first
-        if nafs.is_empty() {
-            return Some(r);
+    for i in (0..naf_length).rev() {
+        let mut t = r + r;
+
+        for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
+            if naf[i] > 0 {
+                t = t + lookup_table.select(naf[i] as usize);
+            } else if naf[i] < 0 {
+                t = t - lookup_table.select(-naf[i] as usize);
+            }
         }
-        let naf_length = nafs[0].len();
 
-        for i in (0..naf_length).rev() {
-            let mut t = r + r;
+        r = t;
+    }
+
+    r
+}
+
+/// Returns the Pippenger window size (in bits) to use for `n` terms.
+fn pippenger_window_size(n: usize) -> usize {
+    match n {
+        0..=31 => 3,
+        32..=127 => 4,
+        128..=499 => 6,
+        500..=799 => 7,
+        800..=3_999 => 8,
+        4_000..=15_999 => 10,
+        _ => 12,
+    }
+}
 
-            for (naf, lookup_table) in nafs.iter().zip(lookup_tables.iter()) {
-                if naf[i] > 0 {
-                    t = t + lookup_table.select(naf[i] as usize);
-                } else if naf[i] < 0 {
-                    t = t - lookup_table.select(-naf[i] as usize);
-                }
+/// Computes the signed radix-2^`w` digits of `scalar`, least significant
+/// first. Each digit lies in `[-2^(w-1), 2^(w-1)]`.
+///
+/// All scalars of a field produce the same number of digits.
+fn signed_radix_digits<G: Group>(scalar: &<G::Field as Field>::Scalar, w: usize) -> Vec<i32> {
+    // required so that the digits (and the carry) fit in i32
+    debug_assert!((1..=16).contains(&w));
+
+    let serialized_scalar = <G::Field>::little_endian_serialize(scalar);
+    let bytes = serialized_scalar.as_ref();
+    let bit_len = bytes.len() * u8::BITS as usize;
+    let bit = |i: usize| -> i32 {
+        if i < bit_len {
+            ((bytes[i / 8] >> (i % 8)) & 1) as i32
+        } else {
+            0
+        }
+    };
+
+    let radix = 1i32 << w;
+    // One extra digit to hold the final carry.
+    let digit_count = div_ceil(bit_len, w) + 1;
+    let mut digits = Vec::with_capacity(digit_count);
+    let mut carry = 0;
+    for d in 0..digit_count {
+        let mut window = carry;
+        for j in 0..w {
+            window += bit(d * w + j) << j;
+        }
+        if window > radix / 2 {
+            digits.push(window - radix);
+            carry = 1;
+        } else {
+            digits.push(window);
+            carry = 0;
+        }
+    }
+    debug_assert_eq!(carry, 0);
+
+    digits
+}
+
+/// Computes a multiscalar multiplication using the Pippenger bucket method,
+/// which is faster than [`straus_multiscalar_mul`] for large inputs.
+/// `scalars` and `elements` must have the same length.
+pub(crate) fn pippenger_multiscalar_mul<G: Group>(
+    scalars: &[<G::Field as Field>::Scalar],
+    elements: &[G::Element],
+) -> G::Element {
+    debug_assert_eq!(scalars.len(), elements.len());
+
+    let w = pippenger_window_size(scalars.len());
+
+    let digits: Vec<_> = scalars
+        .iter()
+        .map(|c| signed_radix_digits::<G>(c, w))
+        .collect();
+
+    let mut r = G::identity();
+
+    // All digit vectors will have the same size, so get it from the first
+    if digits.is_empty() {
+        return r;
+    }
+    let digit_count = digits[0].len();
+
+    // Bucket `b` holds the sum of the elements whose current digit is ±(b+1).
+    let mut buckets = vec![G::identity(); 1 << (w - 1)];
+
+    for i in (0..digit_count).rev() {
+        for _ in 0..w {
+            r = r + r;
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = G::identity();
+        }
+
+        for (digits, element) in digits.iter().zip(elements.iter()) {
+            let digit = digits[i];
+            if digit > 0 {
+                let b = (digit - 1) as usize;
+                buckets[b] = buckets[b] + *element;
+            } else if digit < 0 {
+                let b = (-digit - 1) as usize;
+                buckets[b] = buckets[b] - *element;
+            }
+        }
+
+        // Compute sum((b+1) * buckets[b]) with a running sum, from the
+        // highest bucket down.
+        let mut running_sum = G::identity();
+        let mut window_sum = G::identity();
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum + *bucket;
+            window_sum = window_sum + running_sum;
+        }
+
+        r = r + window_sum;
+    }
+
+    r
+}
+
+/// A precomputed table of multiples of a [`Group`]'s generator, which can be
+/// used to implement [`Group::generator_mul`] for groups whose curve crate does
+/// not provide a basepoint table.
+///
+/// Row `i` holds `j * 16^i * G` for `j` in `0..16`, so that multiplying the
+/// generator by a scalar takes one constant-time lookup and one addition per
+/// 4-bit digit of the scalar, and no doublings.
+///
+/// Building the table is expensive, so it should be built once and lazily,
+/// e.g. by storing it in a `std::sync::OnceLock` static.
+pub struct GeneratorTable<G: Group> {
+    rows: Vec<[G::Element; 16]>,
+}
+
+impl<G> GeneratorTable<G>
+where
+    G: Group,
+    G::Element: ConditionallySelectable,
+{
+    /// Builds the table for [`Group::generator()`].
+    pub fn new() -> Self {
+        let zero = <G::Field>::zero();
+        // Two 4-bit digits per byte of the scalar serialization.
+        let digit_count = <G::Field>::little_endian_serialize(&zero).as_ref().len() * 2;
+
+        let mut rows = Vec::with_capacity(digit_count);
+        let mut base = G::generator();
+        for _ in 0..digit_count {
+            let mut row = [G::identity(); 16];
+            for j in 1..16 {
+                row[j] = row[j - 1] + base;
+            }
+            // 16 * base
+            base = row[15] + base;
+            rows.push(row);
+        }
+
+        Self { rows }
+    }
+
+    /// Computes `scalar * G` in constant time.
+    pub fn mul(&self, scalar: &<G::Field as Field>::Scalar) -> G::Element {
+        let serialized_scalar = <G::Field>::little_endian_serialize(scalar);
+        let bytes = serialized_scalar.as_ref();
+
+        let mut r = G::identity();
+        for (i, row) in self.rows.iter().enumerate() {
+            let digit = if i % 2 == 0 {
+                bytes[i / 2] & 0x0f
+            } else {
+                bytes[i / 2] >> 4
+            };
+
+            // Scan the whole row so that the memory access pattern does not
+            // depend on the (secret) digit.
+            let mut t = G::identity();
+            for (j, entry) in row.iter().enumerate() {
+                t.conditional_assign(entry, (j as u8).ct_eq(&digit));
             }
 
-            r = t;
+            r = r + t;
         }
 
-        Some(r)
+        r
+    }
+}
+
+impl<G> Default for GeneratorTable<G>
+where
+    G: Group,
+    G::Element: ConditionallySelectable,
+{
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 /// Holds odd multiples 1A, 3A, ..., 15A of a point A.
 #[derive(Copy, Clone)]
-pub(crate) struct LookupTable5<C, T> {
+pub(crate) struct LookupTable5<G, T> {
     pub(crate) bytes: [T; 8],
-    pub(crate) _marker: PhantomData<C>,
+    pub(crate) _marker: PhantomData<G>,
 }
 
-impl<C: Ciphersuite, T: Copy> LookupTable5<C, T> {
+impl<G: Group, T: Copy> LookupTable5<G, T> {
     /// Given public, odd \\( x \\) with \\( 0 < x < 2^4 \\), return \\(xA\\).
     pub fn select(&self, x: usize) -> T {
         debug_assert_eq!(x & 1, 1);
@@ -242,17 +465,17 @@ impl<C: Ciphersuite, T: Copy> LookupTable5<C, T> {
     }
 }
 
-impl<C: Ciphersuite, T: Debug> Debug for LookupTable5<C, T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result {
+impl<G: Group, T: Debug> Debug for LookupTable5<G, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result {
         write!(f, "LookupTable5({:?})", self.bytes)
     }
 }
 
-impl<'a, C> From<&'a Element<C>> for LookupTable5<C, Element<C>>
+impl<'a, G> From<&'a G::Element> for LookupTable5<G, G::Element>
 where
-    C: Ciphersuite,
+    G: Group,
 {
-    fn from(A: &'a Element<C>) -> Self {
+    fn from(A: &'a G::Element) -> Self {
         let mut Ai = [*A; 8];
         let A2 = *A + *A;
         for i in 0..7 {
@@ -1,7 +1,21 @@
 //! Serialization support.
 
+#[cfg(feature = "serde")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::{Ciphersuite, Error, Field, Group};
 
+#[cfg(feature = "serialization")]
+mod migration;
+#[cfg(feature = "serialization")]
+mod wire;
+
+#[cfg(feature = "serialization")]
+use migration::Migrate;
+#[cfg(feature = "serialization")]
+use wire::Wire;
+
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
@@ -87,13 +101,15 @@ where
     }
 }
 
-// The short 4-byte ID. Derived as the CRC-32 of the UTF-8
-// encoded ID in big endian format.
-const fn short_id<C>() -> [u8; 4]
+/// Returns the short 4-byte ID of the ciphersuite `C`, which is used in the
+/// header of serialized structs in non human-readable encodings. It is
+/// derived as the CRC-32 of the UTF-8 encoded [`Ciphersuite::ID`], in big
+/// endian format.
+pub const fn short_id<C>() -> [u8; 4]
 where
     C: Ciphersuite,
 {
-    const_crc32::crc32(C::ID.as_bytes()).to_be_bytes()
+    const_crc32_nostd::crc32(C::ID.as_bytes()).to_be_bytes()
 }
 
 /// Serialize a placeholder ciphersuite field with the ciphersuite ID string.
@@ -136,8 +152,12 @@ where
     }
 }
 
-/// Deserialize a version. For now, since there is a single version 0,
-/// simply validate if it's 0.
+/// Deserialize the version in the header of the `serde` representation of
+/// the structs, and check that it is 0.
+///
+/// This version is separate from the [`FORMAT_VERSION`] of the fixed-layout
+/// encoding written by the `serialize()` methods: the `serde` representation
+/// has not changed, and is still at version 0.
 #[cfg(feature = "serde")]
 pub(crate) fn version_deserialize<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
@@ -146,13 +166,24 @@ where
     let version: u8 = serde::de::Deserialize::deserialize(deserializer)?;
     if version != 0 {
         Err(serde::de::Error::custom(
-            "wrong format version, only 0 supported",
+            "wrong serde header version, only 0 supported",
         ))
     } else {
         Ok(version)
     }
 }
 
+/// The format version written by the `serialize()` methods, which produce the
+/// fixed-layout encoding described in the `wire` module.
+#[cfg(feature = "serialization")]
+pub const FORMAT_VERSION: u8 = 1;
+
+/// The format version of the legacy encoding, which is the `postcard`
+/// encoding of the `serde` representation of the structs. It is still
+/// accepted by the `deserialize()` methods.
+#[cfg(feature = "serialization")]
+pub const LEGACY_FORMAT_VERSION: u8 = 0;
+
 // Default byte-oriented serialization for structs that need to be communicated.
 //
 // Note that we still manually implement these methods in each applicable type,
@@ -172,19 +203,170 @@ pub(crate) trait Deserialize<C: Ciphersuite> {
     /// Deserialize the struct from a slice of bytes.
     fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>>
     where
-        Self: std::marker::Sized;
+        Self: core::marker::Sized;
 }
 
 #[cfg(feature = "serialization")]
-impl<T: serde::Serialize, C: Ciphersuite> Serialize<C> for T {
+impl<T: Wire<C>, C: Ciphersuite> Serialize<C> for T {
     fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        postcard::to_stdvec(self).map_err(|_| Error::SerializationError)
+        wire::serialize(self)
     }
 }
 
 #[cfg(feature = "serialization")]
-impl<T: for<'de> serde::Deserialize<'de>, C: Ciphersuite> Deserialize<C> for T {
+impl<T: Migrate<C>, C: Ciphersuite> Deserialize<C> for T {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        postcard::from_bytes(bytes).map_err(|_| Error::DeserializationError)
+        migration::deserialize(bytes)
+    }
+}
+
+/// The header at the start of a struct serialized with its `serialize()`
+/// method.
+#[cfg(feature = "serialization")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HeaderInfo {
+    /// The format version, e.g. [`FORMAT_VERSION`].
+    pub version: u8,
+    /// The short ID of the ciphersuite, see [`short_id()`].
+    pub short_id: [u8; 4],
+}
+
+/// Reads the header at the start of a struct serialized with its
+/// `serialize()` method, without decoding the rest of it.
+///
+/// Returns `None` if `bytes` is too short to hold a header. The header is not
+/// validated; compare its `short_id` with [`short_id()`] to find out which
+/// ciphersuite it belongs to.
+#[cfg(feature = "serialization")]
+pub fn peek_header(bytes: &[u8]) -> Option<HeaderInfo> {
+    // In all format versions, the header starts with a 1-byte version
+    // followed by the short ID.
+    let (&version, rest) = bytes.split_first()?;
+    let short_id = rest.get(..4)?.try_into().ok()?;
+    Some(HeaderInfo { version, short_id })
+}
+
+/// The kind of struct held in a serialized value.
+///
+/// The discriminant is the byte identifying the kind in the header of the
+/// current format.
+#[cfg(feature = "serialization")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum PackageKind {
+    /// A [`crate::keys::SecretShare`].
+    SecretShare = 1,
+    /// A [`crate::keys::KeyPackage`].
+    KeyPackage = 2,
+    /// A [`crate::keys::PublicKeyPackage`].
+    PublicKeyPackage = 3,
+    /// A [`crate::round1::SigningNonces`].
+    SigningNonces = 4,
+    /// A [`crate::round1::SigningCommitments`].
+    SigningCommitments = 5,
+    /// A [`crate::SigningPackage`].
+    SigningPackage = 6,
+    /// A [`crate::keys::dkg::round1::Package`].
+    DkgRound1Package = 7,
+    /// A [`crate::keys::dkg::round2::Package`].
+    DkgRound2Package = 8,
+    /// A [`crate::keys::group::GroupDescriptor`].
+    GroupDescriptor = 9,
+}
+
+#[cfg(feature = "serialization")]
+impl TryFrom<u8> for PackageKind {
+    type Error = ();
+
+    /// Returns the kind identified by `value` in the header of the current
+    /// format, or `Err(())` if there is none.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        [
+            PackageKind::SecretShare,
+            PackageKind::KeyPackage,
+            PackageKind::PublicKeyPackage,
+            PackageKind::SigningNonces,
+            PackageKind::SigningCommitments,
+            PackageKind::SigningPackage,
+            PackageKind::DkgRound1Package,
+            PackageKind::DkgRound2Package,
+            PackageKind::GroupDescriptor,
+        ]
+        .into_iter()
+        .find(|kind| *kind as u8 == value)
+        .ok_or(())
+    }
+}
+
+/// Returns the kind of struct held in `bytes`, which must have been
+/// serialized with the `serialize()` method of a struct of the ciphersuite
+/// `C`.
+///
+/// Returns [`Error::WrongCiphersuite`] if the header belongs to another
+/// ciphersuite (use [`peek_header()`] to find out which), and
+/// [`Error::DeserializationError`] if the kind can not be determined.
+///
+/// The current format names the kind in its header, which is returned
+/// without decoding the rest of `bytes`: deserializing them as that kind may
+/// still fail. The legacy format does not name the kind, so the kinds are
+/// tried in the order they are listed in [`PackageKind`], and the first one
+/// that decodes the whole input is returned.
+#[cfg(feature = "serialization")]
+pub fn package_kind<C: Ciphersuite>(bytes: &[u8]) -> Result<PackageKind, Error<C>> {
+    use crate::keys::{dkg, group::GroupDescriptor, KeyPackage, PublicKeyPackage, SecretShare};
+    use crate::round1::{SigningCommitments, SigningNonces};
+    use crate::SigningPackage;
+
+    /// Whether `bytes` is exactly the encoding of a `T`.
+    fn decodes<C: Ciphersuite, T: Migrate<C>>(bytes: &[u8]) -> bool {
+        migration::deserialize::<C, T>(bytes).is_ok()
     }
+
+    let header = peek_header(bytes).ok_or(Error::DeserializationError)?;
+    if header.short_id != short_id::<C>() {
+        return Err(Error::WrongCiphersuite);
+    }
+    match header.version {
+        FORMAT_VERSION => {
+            return wire::peek_kind(bytes)
+                .and_then(|kind| PackageKind::try_from(kind).ok())
+                .ok_or(Error::DeserializationError)
+        }
+        LEGACY_FORMAT_VERSION => {}
+        _ => return Err(Error::DeserializationError),
+    }
+
+    type Decodes = fn(&[u8]) -> bool;
+    let kinds: [(PackageKind, Decodes); 9] = [
+        (PackageKind::SecretShare, decodes::<C, SecretShare<C>>),
+        (PackageKind::KeyPackage, decodes::<C, KeyPackage<C>>),
+        (
+            PackageKind::PublicKeyPackage,
+            decodes::<C, PublicKeyPackage<C>>,
+        ),
+        (PackageKind::SigningNonces, decodes::<C, SigningNonces<C>>),
+        (
+            PackageKind::SigningCommitments,
+            decodes::<C, SigningCommitments<C>>,
+        ),
+        (PackageKind::SigningPackage, decodes::<C, SigningPackage<C>>),
+        (
+            PackageKind::DkgRound1Package,
+            decodes::<C, dkg::round1::Package<C>>,
+        ),
+        (
+            PackageKind::DkgRound2Package,
+            decodes::<C, dkg::round2::Package<C>>,
+        ),
+        (
+            PackageKind::GroupDescriptor,
+            decodes::<C, GroupDescriptor<C>>,
+        ),
+    ];
+    kinds
+        .into_iter()
+        .find(|(_, decodes)| decodes(bytes))
+        .map(|(kind, _)| kind)
+        .ok_or(Error::DeserializationError)
 }
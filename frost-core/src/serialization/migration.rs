@@ -0,0 +1,180 @@
+//! Decoding of structs serialized with previous format versions.
+//!
+//! Each struct that can be serialized has a registry of decoders, one for each
+//! format version it can be read from (see [`Migrate::decoders()`]). The
+//! decoder of a previous version reads the layout of that version and upgrades
+//! it into the current struct, so that stored key material keeps loading after
+//! the format changes.
+//!
+//! When the layout of a struct changes:
+//!
+//! - bump [`FORMAT_VERSION`] and update its [`Wire`] implementation;
+//! - add a type describing the previous layout, with a [`Wire`] or `serde`
+//!   implementation that reads it;
+//! - override [`Migrate::decoders()`] for the struct, registering a decoder
+//!   that reads the previous layout and fills in what changed.
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use crate::{
+    keys::{
+        dkg, group::GroupDescriptor, KeyPackage, PublicKeyPackage, SecretShare, VerifyingShare,
+    },
+    round1::{SigningCommitments, SigningNonces},
+    Ciphersuite, Error, Header, Identifier, SigningPackage, SigningParameters, SigningTarget,
+    VerifyingKey,
+};
+
+use super::{
+    peek_header, short_id,
+    wire::{self, Wire},
+    FORMAT_VERSION, LEGACY_FORMAT_VERSION,
+};
+
+/// Decodes a whole serialized struct, header included, upgrading it to the
+/// current layout.
+pub(crate) type Decoder<C, T> = fn(&[u8]) -> Result<T, Error<C>>;
+
+/// A struct that can be read from the format versions listed in its registry.
+pub(crate) trait Migrate<C: Ciphersuite>:
+    Wire<C> + for<'de> serde::Deserialize<'de>
+{
+    /// Returns the decoder of each format version the struct can be read
+    /// from, as `(version, decoder)` pairs.
+    ///
+    /// The default is for structs whose layout has not changed since the
+    /// legacy format: the legacy `postcard` encoding and the current one.
+    fn decoders() -> Vec<(u8, Decoder<C, Self>)> {
+        vec![
+            (LEGACY_FORMAT_VERSION, decode_legacy::<C, Self>),
+            (FORMAT_VERSION, wire::deserialize::<C, Self>),
+        ]
+    }
+}
+
+/// Deserializes a `T` from `bytes` with the decoder registered for the
+/// format version in its header.
+pub(crate) fn deserialize<C: Ciphersuite, T: Migrate<C>>(bytes: &[u8]) -> Result<T, Error<C>> {
+    let header = peek_header(bytes).ok_or(Error::DeserializationError)?;
+    if header.short_id != short_id::<C>() {
+        return Err(Error::WrongCiphersuite);
+    }
+    let (_, decode) = T::decoders()
+        .into_iter()
+        .find(|(version, _)| *version == header.version)
+        .ok_or(Error::DeserializationError)?;
+    decode(bytes)
+}
+
+/// Decodes the legacy `postcard` encoding of the `serde` representation of a
+/// `T`, which must take the whole input.
+pub(crate) fn decode_legacy<C, T>(bytes: &[u8]) -> Result<T, Error<C>>
+where
+    C: Ciphersuite,
+    T: for<'de> serde::Deserialize<'de>,
+{
+    match postcard::take_from_bytes::<T>(bytes) {
+        Ok((value, [])) => Ok(value),
+        _ => Err(Error::DeserializationError),
+    }
+}
+
+/// The `serde` representation of a [`PublicKeyPackage`] in the legacy format,
+/// which has no `min_signers` nor `commitment`.
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct PublicKeyPackageV0<C: Ciphersuite> {
+    header: Header<C>,
+    verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+    verifying_key: VerifyingKey<C>,
+}
+
+/// The `serde` representation of a [`SigningPackage`] in the legacy format,
+/// for ciphersuites whose signing parameters were `()` then and were thus not
+/// encoded.
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct SigningPackageV0<C: Ciphersuite> {
+    header: Header<C>,
+    signing_commitments: BTreeMap<Identifier<C>, SigningCommitments<C>>,
+    sig_target: SigningTargetV0,
+}
+
+/// The `serde` representation of a [`SigningTarget`] without signing
+/// parameters.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SigningTargetV0 {
+    #[serde(deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec")]
+    message: Vec<u8>,
+}
+
+impl<C: Ciphersuite> Migrate<C> for SecretShare<C> {}
+
+impl<C: Ciphersuite> Migrate<C> for KeyPackage<C> {}
+
+/// `min_signers` and `commitment` were added after the legacy format; they are
+/// unknown in legacy packages.
+impl<C: Ciphersuite> Migrate<C> for PublicKeyPackage<C> {
+    fn decoders() -> Vec<(u8, Decoder<C, Self>)> {
+        vec![
+            (LEGACY_FORMAT_VERSION, |bytes| {
+                // The `serde` representation still has version 0 in its
+                // header, so its `postcard` encoding is accepted too.
+                let Ok(v0) = decode_legacy::<C, PublicKeyPackageV0<C>>(bytes) else {
+                    return decode_legacy(bytes);
+                };
+                Ok(PublicKeyPackage {
+                    header: v0.header,
+                    verifying_shares: v0.verifying_shares,
+                    verifying_key: v0.verifying_key,
+                    min_signers: None,
+                    commitment: None,
+                })
+            }),
+            (FORMAT_VERSION, wire::deserialize::<C, Self>),
+        ]
+    }
+}
+
+impl<C: Ciphersuite> Migrate<C> for SigningNonces<C> {}
+
+impl<C: Ciphersuite> Migrate<C> for SigningCommitments<C> {}
+
+/// Signing parameters may have been added to a ciphersuite after the legacy
+/// format, so for ciphersuites whose default parameters encode to no bytes,
+/// legacy signing packages without them are read with the default parameters.
+impl<C: Ciphersuite> Migrate<C> for SigningPackage<C> {
+    fn decoders() -> Vec<(u8, Decoder<C, Self>)> {
+        vec![
+            (LEGACY_FORMAT_VERSION, |bytes| {
+                decode_legacy(bytes).or_else(|err| {
+                    if !C::SigningParameters::default().to_bytes().is_empty() {
+                        return Err(err);
+                    }
+                    let v0: SigningPackageV0<C> = decode_legacy(bytes)?;
+                    Ok(SigningPackage {
+                        header: v0.header,
+                        signing_commitments: v0.signing_commitments,
+                        sig_target: SigningTarget::from_message(v0.sig_target.message),
+                    })
+                })
+            }),
+            (FORMAT_VERSION, wire::deserialize::<C, Self>),
+        ]
+    }
+}
+
+impl<C: Ciphersuite> Migrate<C> for dkg::round1::Package<C> {}
+
+impl<C: Ciphersuite> Migrate<C> for dkg::round2::Package<C> {}
+
+/// Group descriptors were added after the legacy format, so they are only
+/// read from the current one.
+impl<C: Ciphersuite> Migrate<C> for GroupDescriptor<C> {
+    fn decoders() -> Vec<(u8, Decoder<C, Self>)> {
+        vec![(FORMAT_VERSION, wire::deserialize::<C, Self>)]
+    }
+}
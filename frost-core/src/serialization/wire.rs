@@ -0,0 +1,524 @@
+//! The fixed-layout encoding produced by the `serialize()` methods.
+//!
+//! Every struct starts with a header made of the format version
+//! ([`FORMAT_VERSION`]), the 4-byte ciphersuite short ID and a byte
+//! identifying the [`PackageKind`]. The fields follow in declaration order,
+//! with no padding and no field tags:
+//!
+//! - Scalars (including identifiers) and group elements use the fixed-size
+//!   encoding defined by the ciphersuite.
+//! - Signatures use [`Ciphersuite::SignatureSerialization`].
+//! - Counts are big-endian `u16`, followed by that many items; maps are
+//!   written as a count followed by the key/value pairs sorted by key.
+//! - Timestamps are big-endian `u64`.
+//! - Byte strings are a big-endian `u32` length followed by the bytes; text
+//!   strings are byte strings holding UTF-8.
+//! - Structs nested in another struct are written without their header.
+//!
+//! The layout of each struct is given in its [`Wire`] implementation below
+//! and in the book.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{
+    keys::{
+        dkg, group::GroupDescriptor, CoefficientCommitment, KeyPackage, PublicKeyPackage,
+        SecretShare, SigningShare, VerifiableSecretSharingCommitment, VerifyingShare,
+    },
+    round1::{Nonce, NonceCommitment, SigningCommitments, SigningNonces},
+    Ciphersuite, Error, Field, Group, Header, Identifier, Signature, SigningPackage,
+    SigningParameters, SigningTarget, VerifyingKey,
+};
+
+use super::{short_id, PackageKind, FORMAT_VERSION};
+
+/// The length of the header: version, ciphersuite short ID and kind.
+const HEADER_LEN: usize = 6;
+
+/// A struct with a fixed-layout encoding.
+pub(crate) trait Wire<C: Ciphersuite>: Sized {
+    /// The kind written in the header when the struct is serialized on its
+    /// own.
+    const KIND: PackageKind;
+
+    /// Writes the fields of the struct, without the header.
+    fn write(&self, writer: &mut Writer<C>);
+
+    /// Reads the fields of the struct, without the header.
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>>;
+}
+
+/// Serializes `value`, header included.
+pub(crate) fn serialize<C: Ciphersuite, T: Wire<C>>(value: &T) -> Result<Vec<u8>, Error<C>> {
+    let mut writer = Writer::<C>::default();
+    writer.bytes.push(FORMAT_VERSION);
+    writer.bytes.extend_from_slice(&short_id::<C>());
+    writer.bytes.push(T::KIND as u8);
+    value.write(&mut writer);
+    writer.finish()
+}
+
+/// Returns the kind byte of the header at the start of `bytes`, without
+/// checking the rest of the header.
+pub(crate) fn peek_kind(bytes: &[u8]) -> Option<u8> {
+    bytes.get(HEADER_LEN - 1).copied()
+}
+
+/// Deserializes a `T` from `bytes`, which must hold exactly one struct
+/// with the current [`FORMAT_VERSION`].
+pub(crate) fn deserialize<C: Ciphersuite, T: Wire<C>>(bytes: &[u8]) -> Result<T, Error<C>> {
+    let ([version, suite @ .., kind], body) = bytes
+        .split_first_chunk::<HEADER_LEN>()
+        .ok_or(Error::DeserializationError)?;
+    if *version != FORMAT_VERSION || *kind != T::KIND as u8 {
+        return Err(Error::DeserializationError);
+    }
+    if *suite != short_id::<C>() {
+        return Err(Error::WrongCiphersuite);
+    }
+
+    let mut reader = Reader {
+        bytes: body,
+        phantom: PhantomData,
+    };
+    let value = T::read(&mut reader)?;
+    if !reader.bytes.is_empty() {
+        return Err(Error::DeserializationError);
+    }
+    Ok(value)
+}
+
+/// Appends the encoding of fields to a buffer.
+pub(crate) struct Writer<C: Ciphersuite> {
+    bytes: Vec<u8>,
+    /// Set if a count or length does not fit its fixed-size encoding.
+    overflow: bool,
+    phantom: PhantomData<C>,
+}
+
+impl<C: Ciphersuite> Default for Writer<C> {
+    fn default() -> Self {
+        Self {
+            bytes: Vec::new(),
+            overflow: false,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<C: Ciphersuite> Writer<C> {
+    fn scalar(&mut self, scalar: &<<C::Group as Group>::Field as Field>::Serialization) {
+        self.bytes.extend_from_slice(scalar.as_ref());
+    }
+
+    fn element(&mut self, element: &<C::Group as Group>::Serialization) {
+        self.bytes.extend_from_slice(element.as_ref());
+    }
+
+    fn count(&mut self, count: usize) {
+        let count = u16::try_from(count).unwrap_or_else(|_| {
+            self.overflow = true;
+            0
+        });
+        self.bytes.extend_from_slice(&count.to_be_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn byte_string(&mut self, bytes: &[u8]) {
+        let len = u32::try_from(bytes.len()).unwrap_or_else(|_| {
+            self.overflow = true;
+            0
+        });
+        self.bytes.extend_from_slice(&len.to_be_bytes());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    fn finish(self) -> Result<Vec<u8>, Error<C>> {
+        if self.overflow {
+            Err(Error::SerializationError)
+        } else {
+            Ok(self.bytes)
+        }
+    }
+}
+
+/// Reads the encoding of fields from a buffer.
+pub(crate) struct Reader<'a, C: Ciphersuite> {
+    bytes: &'a [u8],
+    phantom: PhantomData<C>,
+}
+
+impl<'a, C: Ciphersuite> Reader<'a, C> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error<C>> {
+        let (taken, rest) = self
+            .bytes
+            .split_at_checked(len)
+            .ok_or(Error::DeserializationError)?;
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], Error<C>> {
+        let (taken, rest) = self
+            .bytes
+            .split_first_chunk::<N>()
+            .ok_or(Error::DeserializationError)?;
+        self.bytes = rest;
+        Ok(*taken)
+    }
+
+    fn array<T: TryFrom<Vec<u8>>>(&mut self, len: usize) -> Result<T, Error<C>> {
+        self.take(len)?
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::DeserializationError)
+    }
+
+    fn scalar(&mut self) -> Result<<<C::Group as Group>::Field as Field>::Serialization, Error<C>> {
+        let len = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+            .as_ref()
+            .len();
+        self.array(len)
+    }
+
+    fn element(&mut self) -> Result<<C::Group as Group>::Serialization, Error<C>> {
+        let len = <C::Group>::serialize(&<C::Group>::generator())
+            .as_ref()
+            .len();
+        self.array(len)
+    }
+
+    fn signature(&mut self) -> Result<C::SignatureSerialization, Error<C>> {
+        let len = C::serialize_signature(&Signature {
+            R: <C::Group>::generator(),
+            z: <<C::Group as Group>::Field>::zero(),
+        })
+        .as_ref()
+        .len();
+        self.array(len)
+    }
+
+    fn count(&mut self) -> Result<usize, Error<C>> {
+        Ok(u16::from_be_bytes(self.take_array()?).into())
+    }
+
+    fn u64(&mut self) -> Result<u64, Error<C>> {
+        Ok(u64::from_be_bytes(self.take_array()?))
+    }
+
+    fn byte_string(&mut self) -> Result<&'a [u8], Error<C>> {
+        let len = u32::from_be_bytes(self.take_array()?);
+        self.take(usize::try_from(len).map_err(|_| Error::DeserializationError)?)
+    }
+
+    fn string(&mut self) -> Result<String, Error<C>> {
+        String::from_utf8(self.byte_string()?.to_vec()).map_err(|_| Error::DeserializationError)
+    }
+
+    fn identifier(&mut self) -> Result<Identifier<C>, Error<C>> {
+        Identifier::deserialize(&self.scalar()?).map_err(|_| Error::DeserializationError)
+    }
+
+    fn signing_share(&mut self) -> Result<SigningShare<C>, Error<C>> {
+        SigningShare::deserialize(self.scalar()?).map_err(|_| Error::DeserializationError)
+    }
+
+    fn verifying_share(&mut self) -> Result<VerifyingShare<C>, Error<C>> {
+        VerifyingShare::deserialize(self.element()?).map_err(|_| Error::DeserializationError)
+    }
+
+    fn verifying_key(&mut self) -> Result<VerifyingKey<C>, Error<C>> {
+        VerifyingKey::deserialize(self.element()?).map_err(|_| Error::DeserializationError)
+    }
+
+    fn commitment(&mut self) -> Result<VerifiableSecretSharingCommitment<C>, Error<C>> {
+        let count = self.count()?;
+        let coefficients = (0..count)
+            .map(|_| {
+                CoefficientCommitment::deserialize(self.element()?)
+                    .map_err(|_| Error::DeserializationError)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(VerifiableSecretSharingCommitment(coefficients))
+    }
+}
+
+/// Writes a [`VerifiableSecretSharingCommitment`] as a count followed by the
+/// coefficient commitments.
+fn write_commitment<C: Ciphersuite>(
+    writer: &mut Writer<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) {
+    writer.count(commitment.0.len());
+    for coefficient in &commitment.0 {
+        writer.element(&coefficient.serialize());
+    }
+}
+
+/// `identifier || signing_share || commitment`
+impl<C: Ciphersuite> Wire<C> for SecretShare<C> {
+    const KIND: PackageKind = PackageKind::SecretShare;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.scalar(&self.identifier.serialize());
+        writer.scalar(&self.signing_share.serialize());
+        write_commitment(writer, &self.commitment);
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            identifier: reader.identifier()?,
+            signing_share: reader.signing_share()?,
+            commitment: reader.commitment()?,
+        })
+    }
+}
+
+/// `identifier || signing_share || verifying_share || verifying_key || min_signers (u16)`
+impl<C: Ciphersuite> Wire<C> for KeyPackage<C> {
+    const KIND: PackageKind = PackageKind::KeyPackage;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.scalar(&self.identifier.serialize());
+        writer.scalar(&self.signing_share.serialize());
+        writer.element(&self.verifying_share.serialize());
+        writer.element(&self.verifying_key.serialize());
+        writer.count(self.min_signers.into());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            identifier: reader.identifier()?,
+            signing_share: reader.signing_share()?,
+            verifying_share: reader.verifying_share()?,
+            verifying_key: reader.verifying_key()?,
+            min_signers: reader
+                .count()?
+                .try_into()
+                .map_err(|_| Error::DeserializationError)?,
+        })
+    }
+}
+
+/// `count || (identifier || verifying_share)* || verifying_key || min_signers (u16) || commitment`,
+/// where `min_signers` is 0 and `commitment` is empty if they are not known.
+impl<C: Ciphersuite> Wire<C> for PublicKeyPackage<C> {
+    const KIND: PackageKind = PackageKind::PublicKeyPackage;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.count(self.verifying_shares.len());
+        for (identifier, verifying_share) in &self.verifying_shares {
+            writer.scalar(&identifier.serialize());
+            writer.element(&verifying_share.serialize());
+        }
+        writer.element(&self.verifying_key.serialize());
+        writer.count(self.min_signers.unwrap_or(0).into());
+        match &self.commitment {
+            Some(commitment) => write_commitment(writer, commitment),
+            None => writer.count(0),
+        }
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        let verifying_shares = read_map(reader, |reader| reader.verifying_share())?;
+        let verifying_key = reader.verifying_key()?;
+        let min_signers = match reader.count()? {
+            0 => None,
+            min_signers => Some(
+                min_signers
+                    .try_into()
+                    .map_err(|_| Error::DeserializationError)?,
+            ),
+        };
+        let commitment = reader.commitment()?;
+        Ok(Self {
+            header: Header::default(),
+            verifying_shares,
+            verifying_key,
+            min_signers,
+            commitment: (!commitment.0.is_empty()).then_some(commitment),
+        })
+    }
+}
+
+/// `hiding || binding || commitments`, where the nonces are scalars and
+/// `commitments` is a nested [`SigningCommitments`].
+impl<C: Ciphersuite> Wire<C> for SigningNonces<C> {
+    const KIND: PackageKind = PackageKind::SigningNonces;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.scalar(&self.hiding.serialize());
+        writer.scalar(&self.binding.serialize());
+        self.commitments.write(writer);
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            hiding: Nonce::deserialize(reader.scalar()?)
+                .map_err(|_| Error::DeserializationError)?,
+            binding: Nonce::deserialize(reader.scalar()?)
+                .map_err(|_| Error::DeserializationError)?,
+            commitments: SigningCommitments::read(reader)?,
+        })
+    }
+}
+
+/// `hiding || binding`, where both are group elements.
+impl<C: Ciphersuite> Wire<C> for SigningCommitments<C> {
+    const KIND: PackageKind = PackageKind::SigningCommitments;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.element(&self.hiding.serialize());
+        writer.element(&self.binding.serialize());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        let mut commitment = || {
+            NonceCommitment::deserialize(reader.element()?).map_err(|_| Error::DeserializationError)
+        };
+        Ok(Self {
+            header: Header::default(),
+            hiding: commitment()?,
+            binding: commitment()?,
+        })
+    }
+}
+
+/// `count || (identifier || signing_commitments)* || message || sig_params`,
+/// where `message` and `sig_params` (as encoded by
+/// [`SigningParameters::to_bytes()`]) are byte strings.
+impl<C: Ciphersuite> Wire<C> for SigningPackage<C> {
+    const KIND: PackageKind = PackageKind::SigningPackage;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.count(self.signing_commitments.len());
+        for (identifier, commitments) in &self.signing_commitments {
+            writer.scalar(&identifier.serialize());
+            commitments.write(writer);
+        }
+        writer.byte_string(&self.sig_target.message);
+        writer.byte_string(&self.sig_target.sig_params.to_bytes());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        let signing_commitments = read_map(reader, SigningCommitments::read)?;
+        let message = reader.byte_string()?.to_vec();
+        let sig_params = C::SigningParameters::from_bytes(reader.byte_string()?)
+            .ok_or(Error::DeserializationError)?;
+        let sig_target = SigningTarget::new(message, sig_params);
+        sig_target.check()?;
+        Ok(Self {
+            header: Header::default(),
+            signing_commitments,
+            sig_target,
+        })
+    }
+}
+
+/// `commitment || proof_of_knowledge`
+impl<C: Ciphersuite> Wire<C> for dkg::round1::Package<C> {
+    const KIND: PackageKind = PackageKind::DkgRound1Package;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        write_commitment(writer, &self.commitment);
+        writer
+            .bytes
+            .extend_from_slice(self.proof_of_knowledge.serialize().as_ref());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            commitment: reader.commitment()?,
+            proof_of_knowledge: Signature::deserialize(reader.signature()?)
+                .map_err(|_| Error::DeserializationError)?,
+        })
+    }
+}
+
+/// `signing_share`
+impl<C: Ciphersuite> Wire<C> for dkg::round2::Package<C> {
+    const KIND: PackageKind = PackageKind::DkgRound2Package;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.scalar(&self.signing_share.serialize());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            signing_share: reader.signing_share()?,
+        })
+    }
+}
+
+/// `min_signers (u16) || max_signers (u16) || count || (identifier || name)* ||
+/// commitment || created_at (u64) || description`, where the names and the
+/// description are text strings. The descriptor is checked with
+/// [`GroupDescriptor::verify()`] when read.
+impl<C: Ciphersuite> Wire<C> for GroupDescriptor<C> {
+    const KIND: PackageKind = PackageKind::GroupDescriptor;
+
+    fn write(&self, writer: &mut Writer<C>) {
+        writer.count(self.min_signers.into());
+        writer.count(self.max_signers.into());
+        writer.count(self.participants.len());
+        for (identifier, name) in &self.participants {
+            writer.scalar(&identifier.serialize());
+            writer.byte_string(name.as_bytes());
+        }
+        write_commitment(writer, &self.commitment);
+        writer.u64(self.created_at);
+        writer.byte_string(self.description.as_bytes());
+    }
+
+    fn read(reader: &mut Reader<'_, C>) -> Result<Self, Error<C>> {
+        let descriptor = Self {
+            header: Header::default(),
+            min_signers: reader
+                .count()?
+                .try_into()
+                .map_err(|_| Error::DeserializationError)?,
+            max_signers: reader
+                .count()?
+                .try_into()
+                .map_err(|_| Error::DeserializationError)?,
+            participants: read_map(reader, |reader| reader.string())?,
+            commitment: reader.commitment()?,
+            created_at: reader.u64()?,
+            description: reader.string()?,
+        };
+        descriptor.verify()?;
+        Ok(descriptor)
+    }
+}
+
+/// Reads a count followed by that many identifiers and values, which must be
+/// sorted by identifier without duplicates so that each map has a single
+/// encoding.
+fn read_map<C: Ciphersuite, V>(
+    reader: &mut Reader<'_, C>,
+    mut read_value: impl FnMut(&mut Reader<'_, C>) -> Result<V, Error<C>>,
+) -> Result<BTreeMap<Identifier<C>, V>, Error<C>> {
+    let count = reader.count()?;
+    let mut map = BTreeMap::new();
+    for _ in 0..count {
+        let identifier = reader.identifier()?;
+        let value = read_value(reader)?;
+        if map
+            .last_key_value()
+            .is_some_and(|(last, _)| *last >= identifier)
+        {
+            return Err(Error::DeserializationError);
+        }
+        map.insert(identifier, value);
+    }
+    Ok(map)
+}
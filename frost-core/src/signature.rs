@@ -76,8 +76,8 @@ where
     }
 }
 
-impl<C: Ciphersuite> std::fmt::Debug for Signature<C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<C: Ciphersuite> core::fmt::Debug for Signature<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.debug_struct("Signature")
             .field("R", &hex::encode(<C::Group>::serialize(&self.R).as_ref()))
             .field(
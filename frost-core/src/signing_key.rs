@@ -47,23 +47,87 @@ where
     }
 
     /// Create a signature on the given `sig_target` using this `SigningKey`.
+    ///
+    /// This does not check that the signing parameters accept the message; use
+    /// [`SigningKey::try_sign()`] with parameters that may reject it, e.g. ones
+    /// selecting a prehash variant.
     pub fn sign<R: RngCore + CryptoRng>(
         &self,
         mut rng: R,
         sig_target: impl Into<SigningTarget<C>>,
     ) -> Signature<C> {
+        let k = random_nonzero::<C, R>(&mut rng);
+        self.sign_with_nonce(k, &sig_target.into())
+    }
+
+    /// Like [`SigningKey::sign()`], but returns
+    /// [`Error::InvalidSigningParameters`] if the signing parameters do not
+    /// accept the message, see
+    /// [`SigningParameters::accepts_message()`](crate::SigningParameters::accepts_message).
+    pub fn try_sign<R: RngCore + CryptoRng>(
+        &self,
+        rng: R,
+        sig_target: impl Into<SigningTarget<C>>,
+    ) -> Result<Signature<C>, Error<C>> {
+        let sig_target = sig_target.into();
+        sig_target.check()?;
+        Ok(self.sign(rng, sig_target))
+    }
+
+    /// Create a signature on the given `sig_target` using this `SigningKey`,
+    /// with a nonce derived deterministically from the key and the signing
+    /// target by [`Ciphersuite::deterministic_nonce()`], so that signing the
+    /// same target twice gives the same signature.
+    ///
+    /// This is meant for single-signer use of a key, e.g. one reconstructed
+    /// from shares, when reproducible signatures are needed. Never use the key
+    /// to sign the same target with [`SigningKey::sign()`] as well.
+    ///
+    /// Returns [`Error::InvalidSigningParameters`] if the signing parameters do
+    /// not accept the message, as [`SigningKey::try_sign()`] does.
+    pub fn sign_deterministic(
+        &self,
+        sig_target: impl Into<SigningTarget<C>>,
+    ) -> Result<Signature<C>, Error<C>> {
+        self.sign_deterministic_with_aux(&[], sig_target)
+    }
+
+    /// Like [`SigningKey::sign_deterministic()`], but also derives the nonce
+    /// from `aux`, e.g. the auxiliary random data of BIP340.
+    pub fn sign_deterministic_with_aux(
+        &self,
+        aux: &[u8],
+        sig_target: impl Into<SigningTarget<C>>,
+    ) -> Result<Signature<C>, Error<C>> {
         let sig_target = sig_target.into();
+        sig_target.check()?;
+
+        let public = VerifyingKey::<C>::from(*self);
+        let secret = <C>::effective_secret_key(self.scalar, &public, &sig_target.sig_params);
+        let k = <C>::deterministic_nonce(&secret, &public, aux, &sig_target);
+
+        Ok(self.sign_with_nonce(k, &sig_target))
+    }
 
+    /// Create a signature on `sig_target` with the nonce `k`.
+    ///
+    /// The nonce must be secret and never be used to sign another target.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
+    pub(crate) fn sign_with_nonce(
+        &self,
+        mut k: Scalar<C>,
+        sig_target: &SigningTarget<C>,
+    ) -> Signature<C> {
         let public = VerifyingKey::<C>::from(*self);
         let secret = <C>::effective_secret_key(self.scalar, &public, &sig_target.sig_params);
 
-        let mut k = random_nonzero::<C, R>(&mut rng);
-        let mut R = <C::Group>::generator() * k;
+        let mut R = <C::Group>::generator_mul(&k);
         k = <C>::effective_nonce_secret(k, &R);
         R = <C>::effective_nonce_element(R);
 
         // Generate Schnorr challenge
-        let c: Challenge<C> = <C>::challenge(&R, &public, &sig_target);
+        let c: Challenge<C> = <C>::challenge(&R, &public, sig_target);
 
         <C>::single_sig_finalize(k, R, secret, &c, &public, &sig_target.sig_params)
     }
@@ -81,11 +145,11 @@ where
     }
 }
 
-impl<C> std::fmt::Debug for SigningKey<C>
+impl<C> core::fmt::Debug for SigningKey<C>
 where
     C: Ciphersuite,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SigningKey").field(&"<redacted>").finish()
     }
 }
@@ -96,7 +160,7 @@ where
 {
     fn from(signing_key: &SigningKey<C>) -> Self {
         VerifyingKey {
-            element: C::Group::generator() * signing_key.scalar,
+            element: C::Group::generator_mul(&signing_key.scalar),
         }
     }
 }
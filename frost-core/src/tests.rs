@@ -5,7 +5,10 @@
 pub mod batch;
 pub mod ciphersuite_generic;
 pub mod coefficient_commitment;
+pub mod group_descriptor;
 pub mod helpers;
+#[cfg(feature = "keystore")]
+pub mod keystore;
 pub mod proptests;
 pub mod repairable;
 pub mod vectors;
@@ -55,9 +55,127 @@ pub fn bad_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     }
 }
 
+/// Test batch verification with a batch large enough to be split into
+/// several partial multiscalar multiplications when the `parallel` feature is
+/// enabled, with the invalid signature (if any) in the last one.
+pub fn large_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let n = 100;
+    let mut items = Vec::with_capacity(n);
+    for _ in 0..n {
+        let sk = SigningKey::new(&mut rng);
+        let vk = VerifyingKey::<C>::from(&sk);
+        let msg = b"BatchVerifyTest";
+        let sig = sk.sign(&mut rng, &msg[..]);
+        items.push(batch::Item::<C>::from((vk, sig, msg)));
+    }
+
+    let mut batch = batch::Verifier::<C>::new();
+    for item in items.iter().cloned() {
+        batch.queue(item);
+    }
+    assert!(batch.verify(&mut rng).is_ok());
+
+    let sk = SigningKey::new(&mut rng);
+    let vk = VerifyingKey::<C>::from(&sk);
+    let sig = sk.sign(&mut rng, b"bad");
+    let mut batch = batch::Verifier::<C>::new();
+    for item in items {
+        batch.queue(item);
+    }
+    batch.queue((vk, sig, b"BatchVerifyTest"));
+    assert!(batch.verify(rng).is_err());
+}
+
+/// Test locating the invalid signatures of a batch with a Ciphersuite.
+pub fn batch_verify_and_locate<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    for bad_indices in [vec![], vec![0], vec![31], vec![1, 2, 17, 30]] {
+        let mut batch = batch::Verifier::<C>::new();
+        for i in 0..32 {
+            let sk = SigningKey::new(&mut rng);
+            let vk = VerifyingKey::<C>::from(&sk);
+            let msg = b"BatchVerifyTest";
+            let sig = if bad_indices.contains(&i) {
+                sk.sign(&mut rng, b"bad")
+            } else {
+                sk.sign(&mut rng, &msg[..])
+            };
+            batch.queue((vk, sig, msg));
+        }
+
+        match batch.verify_and_locate(&mut rng) {
+            Ok(()) => assert!(bad_indices.is_empty()),
+            Err(invalid) => assert_eq!(invalid, bad_indices),
+        }
+    }
+}
+
 /// Test if the empty batch fails to validate.
 /// Test case from NCC audit.
 pub fn empty_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(rng: R) {
     let batch = batch::Verifier::<C>::new();
     assert!(batch.verify(rng).is_err());
 }
+
+/// Test verification of items decoded from their encodings with a
+/// Ciphersuite, under its default verification mode.
+pub fn batch_verify_from_encodings<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let mode = C::VerificationMode::default();
+    let mut batch = batch::Verifier::<C>::with_mode(mode);
+    for _ in 0..4 {
+        let sk = SigningKey::new(&mut rng);
+        let vk = VerifyingKey::<C>::from(&sk);
+        let msg = b"BatchVerifyTest";
+        let sig = sk.sign(&mut rng, &msg[..]);
+        assert!(vk.verify_with_mode(mode, msg, sig.serialize()).is_ok());
+        assert!(vk.verify_with_mode(mode, b"bad", sig.serialize()).is_err());
+
+        let item = batch::Item::from_encodings(vk.serialize(), sig.serialize(), msg).unwrap();
+        assert!(item.clone().verify_single_with_mode(mode).is_ok());
+        batch.queue(item);
+    }
+    assert!(batch.verify(rng).is_ok());
+}
+
+/// Test that the ciphersuite's multiscalar multiplication matches the naive
+/// computation for a range of input sizes.
+pub fn check_vartime_multiscalar_mul<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    for n in [0, 1, 2, 3, 16, 33, crate::scalar_mul::PIPPENGER_THRESHOLD] {
+        let scalars: Vec<_> = (0..n)
+            .map(|_| <<C::Group as Group>::Field>::random(&mut rng))
+            .collect();
+        let elements: Vec<_> = (0..n)
+            .map(|_| C::Group::generator() * <<C::Group as Group>::Field>::random(&mut rng))
+            .collect();
+
+        let expected = scalars
+            .iter()
+            .zip(elements.iter())
+            .fold(C::Group::identity(), |acc, (s, e)| acc + *e * *s);
+
+        assert!(C::Group::vartime_multiscalar_mul(&scalars, &elements) == expected);
+    }
+}
+
+/// Test that the Pippenger multiscalar multiplication matches the Straus one
+/// for the smallest number of terms that selects each of its window sizes.
+pub fn check_pippenger_window_sizes<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    // A few random elements; the others are sums of previous ones, which is
+    // much cheaper than a scalar multiplication for each of them.
+    let mut elements: Vec<_> = (0..8)
+        .map(|_| C::Group::generator() * <<C::Group as Group>::Field>::random(&mut rng))
+        .collect();
+    for n in [32, 128, 500, 800, 4_000, 16_000] {
+        while elements.len() < n {
+            let len = elements.len();
+            elements.push(elements[len - 1] + elements[len - 8]);
+        }
+        let scalars: Vec<_> = (0..n)
+            .map(|_| <<C::Group as Group>::Field>::random(&mut rng))
+            .collect();
+
+        assert!(
+            crate::scalar_mul::pippenger_multiscalar_mul::<C::Group>(&scalars, &elements)
+                == crate::scalar_mul::straus_multiscalar_mul::<C::Group>(&scalars, &elements)
+        );
+    }
+}
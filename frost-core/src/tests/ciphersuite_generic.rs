@@ -116,6 +116,7 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
         &mut rng,
     )
     .unwrap();
+    assert_eq!(pubkeys.min_signers, Some(min_signers));
 
     // Verifies the secret shares from the dealer
     let mut key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> =
@@ -127,11 +128,14 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
     }
     // Check if it fails with not enough signers. Usually this would return an
     // error before even running the signing procedure, because `KeyPackage`
-    // contains the correct `min_signers` value and the signing procedure checks
-    // if the number of shares is at least `min_signers`. To bypass the check
-    // and test if the protocol itself fails with not enough signers, we modify
-    // the `KeyPackages`s, decrementing their saved `min_signers` value before
+    // and `PublicKeyPackage` contain the correct `min_signers` value and the
+    // signing procedure checks if the number of shares is at least
+    // `min_signers`. To bypass the check and test if the protocol itself fails
+    // with not enough signers, we modify the `KeyPackages`s and the
+    // `PublicKeyPackage`, decrementing their saved `min_signers` value before
     // running the signing procedure.
+    let mut decremented_pubkeys = pubkeys.clone();
+    decremented_pubkeys.min_signers = Some(min_signers - 1);
     let r = check_sign(
         min_signers - 1,
         key_packages
@@ -145,7 +149,7 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
             })
             .collect(),
         &mut rng,
-        pubkeys.clone(),
+        decremented_pubkeys,
         signing_target.clone(),
     );
     assert_eq!(r, Err(Error::InvalidSignature));
@@ -225,6 +229,7 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
     // - take one (unused) commitment per signing participant
     let mut signature_shares = BTreeMap::new();
     let signing_package = frost::SigningPackage::new(commitments_map, signing_target.clone());
+    pubkey_package.validate_signing_package(&signing_package)?;
 
     ////////////////////////////////////////////////////////////////////////////
     // Round 2: each participant generates their signature share
@@ -256,6 +261,8 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
         header: pubkey_package.header,
         verifying_shares: BTreeMap::new(),
         verifying_key: pubkey_package.verifying_key,
+        min_signers: pubkey_package.min_signers,
+        commitment: pubkey_package.commitment,
     };
 
     check_aggregate_errors(
@@ -274,11 +281,17 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
         .verify(signing_target.clone(), &group_signature)?;
 
     // Check that the effective verifying key can be verified against the raw message,
-    // without exposing the SigningParameters.
-    pubkey_package
+    // without exposing the SigningParameters. This only holds for parameters
+    // that tweak the key; others (e.g. Ed25519 context strings) are bound to
+    // the signature through the challenge instead.
+    let effective_key = pubkey_package
         .verifying_key
-        .effective_key(signing_target.sig_params())
-        .verify(signing_target.message(), &group_signature)?;
+        .effective_key(signing_target.sig_params());
+    if effective_key != pubkey_package.verifying_key
+        || *signing_target.sig_params() == C::SigningParameters::default()
+    {
+        effective_key.verify(signing_target.message(), &group_signature)?;
+    }
 
     // Check that the threshold signature can be verified by the group public
     // key (the verification key) from KeyPackage.verifying_key
@@ -328,6 +341,18 @@ fn check_aggregate_errors<C: Ciphersuite + PartialEq>(
         signature_shares.clone(),
         pubkey_package.clone(),
     );
+    #[cfg(feature = "cheater-detection")]
+    check_aggregate_corrupted_shares_reports_first(
+        signing_package.clone(),
+        signature_shares.clone(),
+        pubkey_package.clone(),
+    );
+
+    check_aggregate_not_enough_shares(
+        signing_package.clone(),
+        signature_shares.clone(),
+        pubkey_package.clone(),
+    );
 
     check_aggregate_invalid_share_identifier_for_verifying_shares(
         signing_package,
@@ -336,6 +361,29 @@ fn check_aggregate_errors<C: Ciphersuite + PartialEq>(
     );
 }
 
+/// Check that the coordinator rejects signing packages and signature shares
+/// from fewer participants than the threshold in the `PublicKeyPackage`.
+fn check_aggregate_not_enough_shares<C: Ciphersuite + PartialEq>(
+    signing_package: frost::SigningPackage<C>,
+    mut signature_shares: BTreeMap<frost::Identifier<C>, frost::round2::SignatureShare<C>>,
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+) {
+    let mut commitments = signing_package.signing_commitments().clone();
+    let id = *commitments.keys().next().unwrap();
+    commitments.remove(&id);
+    signature_shares.remove(&id);
+    let signing_package = frost::SigningPackage::new(commitments, signing_package.sig_target);
+
+    assert_eq!(
+        pubkey_package.validate_signing_package(&signing_package),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkey_package),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+}
+
 fn check_aggregate_corrupted_share<C: Ciphersuite + PartialEq>(
     signing_package: frost::SigningPackage<C>,
     mut signature_shares: BTreeMap<frost::Identifier<C>, frost::round2::SignatureShare<C>>,
@@ -350,6 +398,23 @@ fn check_aggregate_corrupted_share<C: Ciphersuite + PartialEq>(
     assert_eq!(e, Error::InvalidSignatureShare { culprit: id });
 }
 
+/// Check that when several shares are corrupted, the culprit reported is the
+/// first one in identifier order, even if the shares are verified in parallel.
+fn check_aggregate_corrupted_shares_reports_first<C: Ciphersuite + PartialEq>(
+    signing_package: frost::SigningPackage<C>,
+    mut signature_shares: BTreeMap<frost::Identifier<C>, frost::round2::SignatureShare<C>>,
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+) {
+    let one = <<C as Ciphersuite>::Group as Group>::Field::one();
+    // Corrupt all shares but the first one
+    for share in signature_shares.values_mut().skip(1) {
+        share.share = share.share + one;
+    }
+    let id = *signature_shares.keys().nth(1).unwrap();
+    let e = frost::aggregate(&signing_package, &signature_shares, &pubkey_package).unwrap_err();
+    assert_eq!(e, Error::InvalidSignatureShare { culprit: id });
+}
+
 /// Test NCC-E008263-4VP audit finding (PublicKeyPackage).
 /// Note that the SigningPackage part of the finding is not currently reachable
 /// since it's caught by `compute_lagrange_coefficient()`, and the Binding Factor
@@ -512,6 +577,14 @@ where
             &received_round2_packages[&participant_identifier],
         )
         .unwrap();
+        assert_eq!(
+            pubkey_package_for_participant.min_signers,
+            Some(min_signers)
+        );
+        pubkey_package_for_participant.verify().unwrap();
+        pubkey_package_for_participant
+            .verify_key_package(&key_package)
+            .unwrap();
         verifying_keys.insert(participant_identifier, key_package.verifying_share);
         // Test if all verifying_key are equal
         if let Some(previous_verifying_key) = verifying_key {
@@ -528,7 +601,11 @@ where
         assert!(verifying_keys_for_participant.verifying_shares == verifying_keys);
     }
 
-    let pubkeys = frost::keys::PublicKeyPackage::new(verifying_keys, verifying_key.unwrap());
+    let pubkeys = frost::keys::PublicKeyPackage::new_with_min_signers(
+        verifying_keys,
+        verifying_key.unwrap(),
+        Some(min_signers),
+    );
 
     // Proceed with the signing test.
     check_sign(min_signers, key_packages, rng, pubkeys, signing_target).unwrap()
@@ -837,3 +914,199 @@ pub fn check_sign_with_incorrect_commitments<C: Ciphersuite, R: RngCore + Crypto
     assert!(signature_share.is_err());
     assert!(signature_share == Err(Error::IncorrectCommitment))
 }
+
+/// Checks that the ciphersuite's generator multiplication matches the plain
+/// scalar multiplication of the generator.
+pub fn check_generator_mul<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let zero = <<C::Group as Group>::Field>::zero();
+    let one = <<C::Group as Group>::Field>::one();
+    let mut scalars = vec![zero, one, zero - one];
+    for _ in 0..16 {
+        scalars.push(<<C::Group as Group>::Field>::random(&mut rng));
+    }
+
+    for scalar in scalars {
+        assert!(C::Group::generator_mul(&scalar) == C::Group::generator() * scalar);
+    }
+}
+
+/// Test checking a [`PublicKeyPackage`] against its commitment, and the
+/// [`KeyPackage`](frost::keys::KeyPackage)s of the group against it.
+pub fn check_public_key_package_verify<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, R>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(identifier, share)| {
+            (
+                identifier,
+                frost::keys::KeyPackage::try_from(share).unwrap(),
+            )
+        })
+        .collect();
+
+    pubkeys.verify().unwrap();
+    for (identifier, key_package) in &key_packages {
+        pubkeys.verify_key_package(key_package).unwrap();
+        assert_eq!(
+            pubkeys.derive_verifying_share(*identifier).unwrap(),
+            pubkeys.verifying_shares()[identifier]
+        );
+    }
+
+    // A tampered verifying share does not match the commitment.
+    let (identifier, key_package) = key_packages.iter().next().unwrap();
+    let mut tampered = pubkeys.clone();
+    tampered.verifying_shares.insert(
+        *identifier,
+        *key_packages.values().nth(1).unwrap().verifying_share(),
+    );
+    assert_eq!(tampered.verify(), Err(Error::InconsistentCommitment));
+    assert_eq!(
+        tampered.verify_key_package(key_package),
+        Err(Error::InconsistentCommitment)
+    );
+
+    // Neither does a wrong threshold.
+    let mut tampered = pubkeys.clone();
+    tampered.min_signers = Some(2);
+    assert_eq!(tampered.verify(), Err(Error::InvalidMinSigners));
+    assert_eq!(
+        tampered.verify_key_package(key_package),
+        Err(Error::InvalidMinSigners)
+    );
+
+    // Key packages of another group are rejected.
+    let (other_shares, _) = frost::keys::generate_with_dealer::<C, R>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let other_key_package =
+        frost::keys::KeyPackage::try_from(other_shares[identifier].clone()).unwrap();
+    assert_eq!(
+        pubkeys.verify_key_package(&other_key_package),
+        Err(Error::InconsistentCommitment)
+    );
+
+    // Without the commitment there is nothing to check against.
+    let without_commitment = PublicKeyPackage::new_with_min_signers(
+        pubkeys.verifying_shares().clone(),
+        *pubkeys.verifying_key(),
+        *pubkeys.min_signers(),
+    );
+    assert_eq!(
+        without_commitment.verify(),
+        Err(Error::UnknownGroupCommitment)
+    );
+    assert_eq!(
+        without_commitment.derive_verifying_share(*identifier),
+        Err(Error::UnknownGroupCommitment)
+    );
+    without_commitment.verify_key_package(key_package).unwrap();
+}
+
+/// Test that a [`PublicKeyPackage`] survives a round trip through `postcard`
+/// and JSON whether or not its threshold and commitment are known.
+#[cfg(feature = "serialization")]
+pub fn check_public_key_package_serde_round_trip<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let (_, pubkeys) = frost::keys::generate_with_dealer::<C, R>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    for (min_signers, commitment) in [
+        (Some(3), pubkeys.commitment.clone()),
+        (None, pubkeys.commitment.clone()),
+        (Some(3), None),
+        (None, None),
+    ] {
+        let public_key_package = PublicKeyPackage {
+            min_signers,
+            commitment,
+            ..pubkeys.clone()
+        };
+
+        let bytes = postcard::to_allocvec(&public_key_package).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<PublicKeyPackage<C>>(&bytes).unwrap(),
+            public_key_package
+        );
+        assert_eq!(
+            PublicKeyPackage::deserialize(&bytes).unwrap(),
+            public_key_package
+        );
+
+        let json = serde_json::to_string(&public_key_package).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PublicKeyPackage<C>>(&json).unwrap(),
+            public_key_package
+        );
+    }
+}
+
+/// Test deterministic single-signer signing with a key reconstructed from the
+/// shares of a group.
+pub fn check_sign_deterministic<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+    signing_target: SigningTarget<C>,
+) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, R>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: Vec<_> = shares
+        .into_values()
+        .take(3)
+        .map(|share| frost::keys::KeyPackage::try_from(share).unwrap())
+        .collect();
+    let signing_key = frost::keys::reconstruct(&key_packages).unwrap();
+
+    let signature = signing_key
+        .sign_deterministic(signing_target.clone())
+        .unwrap();
+    pubkeys
+        .verifying_key()
+        .verify(signing_target.clone(), &signature)
+        .unwrap();
+
+    // Signing the same target gives the same signature.
+    assert_eq!(
+        signing_key
+            .sign_deterministic(signing_target.clone())
+            .unwrap(),
+        signature
+    );
+
+    // Signing another message, with other auxiliary data, or with a random
+    // nonce does not.
+    let other_target = SigningTarget::new(b"other message", signing_target.sig_params().clone());
+    assert_ne!(
+        signing_key.sign_deterministic(other_target).unwrap(),
+        signature
+    );
+    let aux_signature = signing_key
+        .sign_deterministic_with_aux(&[1; 32], signing_target.clone())
+        .unwrap();
+    assert_ne!(aux_signature, signature);
+    pubkeys
+        .verifying_key()
+        .verify(signing_target.clone(), &aux_signature)
+        .unwrap();
+    assert_ne!(signing_key.sign(&mut rng, signing_target), signature);
+}
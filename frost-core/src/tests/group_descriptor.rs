@@ -0,0 +1,142 @@
+//! Ciphersuite-generic tests for the group descriptor.
+
+use alloc::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate as frost;
+use crate::{
+    keys::{group::GroupDescriptor, KeyPackage},
+    Ciphersuite, Error, Identifier,
+};
+
+/// Test building a [`GroupDescriptor`] for a group generated with a dealer
+/// and checking its key packages against it.
+pub fn check_group_descriptor<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let participants =
+        GroupDescriptor::<C>::derive_participants(["alice", "bob", "carol"]).unwrap();
+    let identifiers: Vec<_> = participants.keys().copied().collect();
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::Custom(&identifiers),
+        &mut rng,
+    )
+    .unwrap();
+    let commitment = shares.values().next().unwrap().commitment().clone();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(identifier, share)| (identifier, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let descriptor = GroupDescriptor::new(
+        2,
+        3,
+        participants,
+        commitment.clone(),
+        1_700_000_000,
+        "treasury".into(),
+    )
+    .unwrap();
+    assert_eq!(
+        descriptor.identifier("bob"),
+        Some(Identifier::derive(b"bob").unwrap())
+    );
+    assert_eq!(descriptor.identifier("dave"), None);
+    assert_eq!(
+        descriptor.verifying_key().unwrap(),
+        *pubkeys.verifying_key()
+    );
+
+    for key_package in key_packages.values() {
+        descriptor.verify_key_package(key_package).unwrap();
+    }
+    descriptor.verify_public_key_package(&pubkeys).unwrap();
+
+    // The descriptor survives a round trip through JSON.
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&descriptor).unwrap();
+        let decoded: GroupDescriptor<C> = serde_json::from_str(&json).unwrap();
+        decoded.verify().unwrap();
+        assert_eq!(decoded, descriptor);
+
+        let mut value = serde_json::to_value(&descriptor).unwrap();
+        value["max_signers"] = 4.into();
+        assert!(serde_json::from_value::<GroupDescriptor<C>>(value).is_err());
+    }
+
+    // So does its own encoding, and inconsistent encodings are rejected.
+    #[cfg(feature = "serialization")]
+    {
+        let bytes = descriptor.serialize().unwrap();
+        assert_eq!(GroupDescriptor::deserialize(&bytes).unwrap(), descriptor);
+
+        let mut inconsistent = descriptor.clone();
+        inconsistent.max_signers = 4;
+        let bytes = inconsistent.serialize().unwrap();
+        assert_eq!(
+            GroupDescriptor::<C>::deserialize(&bytes),
+            Err(Error::IncorrectNumberOfIdentifiers)
+        );
+    }
+
+    // Inconsistent descriptors are rejected.
+    assert_eq!(
+        GroupDescriptor::new(
+            2,
+            4,
+            descriptor.participants().clone(),
+            commitment.clone(),
+            0,
+            "".into(),
+        ),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    assert_eq!(
+        GroupDescriptor::new(
+            3,
+            3,
+            descriptor.participants().clone(),
+            commitment,
+            0,
+            "".into(),
+        ),
+        Err(Error::InvalidMinSigners)
+    );
+    assert_eq!(
+        GroupDescriptor::<C>::derive_participants(["alice", "alice"]),
+        Err(Error::DuplicatedIdentifier)
+    );
+
+    // Key material of another group is rejected.
+    let (other_shares, other_pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::Custom(&identifiers),
+        &mut rng,
+    )
+    .unwrap();
+    let other_key_package =
+        KeyPackage::try_from(other_shares.into_values().next().unwrap()).unwrap();
+    assert_eq!(
+        descriptor.verify_key_package(&other_key_package),
+        Err(Error::InconsistentCommitment)
+    );
+    assert_eq!(
+        descriptor.verify_public_key_package(&other_pubkeys),
+        Err(Error::InconsistentCommitment)
+    );
+
+    let mut key_package = key_packages.values().next().unwrap().clone();
+    key_package.min_signers = 3;
+    assert_eq!(
+        descriptor.verify_key_package(&key_package),
+        Err(Error::InvalidMinSigners)
+    );
+    key_package.identifier = Identifier::derive(b"dave").unwrap();
+    assert_eq!(
+        descriptor.verify_key_package(&key_package),
+        Err(Error::UnknownIdentifier)
+    );
+}
@@ -0,0 +1,112 @@
+//! Ciphersuite-generic tests for the password-encrypted keystore.
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate as frost;
+use crate::{
+    keys::KeyPackage,
+    keystore::{
+        decrypt_signing_nonces, encrypt_signing_nonces, KdfParams, KeyPackageStore, MAX_KDF_M_COST,
+        MAX_KDF_T_COST,
+    },
+    round1, Ciphersuite, Error,
+};
+
+/// Test encrypting and decrypting a [`KeyPackageStore`] and
+/// [`round1::SigningNonces`] with a Ciphersuite.
+pub fn check_keystore<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    // Use cheap parameters so that the test runs quickly.
+    let params = KdfParams::new(64, 1, 1);
+
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let share = shares.into_values().next().unwrap();
+    let key_package = KeyPackage::try_from(share).unwrap();
+
+    for store in [
+        KeyPackageStore::new(key_package.clone(), None, vec![]),
+        KeyPackageStore::new(key_package.clone(), Some(pubkeys), b"metadata".to_vec()),
+    ] {
+        let bytes = store.encrypt(b"password", &params, &mut rng).unwrap();
+        assert_eq!(
+            KeyPackageStore::decrypt(&bytes, b"password").unwrap(),
+            store
+        );
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&bytes, b"wrong password"),
+            Err(Error::DecryptionError)
+        );
+        // Nonces can't be loaded from a key package container.
+        assert_eq!(
+            decrypt_signing_nonces::<C>(&bytes, b"password"),
+            Err(Error::DeserializationError)
+        );
+
+        // Changing the ciphersuite is detected before decrypting.
+        let mut modified = bytes.clone();
+        modified[1] ^= 0xff;
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&modified, b"password"),
+            Err(Error::WrongCiphersuite)
+        );
+
+        // The key derivation parameters and the contents are authenticated.
+        let mut modified = bytes.clone();
+        modified[9] ^= 1;
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&modified, b"password"),
+            Err(Error::DecryptionError)
+        );
+        // Key derivation parameters over the maxima are refused before
+        // deriving the key.
+        let mut modified = bytes.clone();
+        modified[6..10].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&modified, b"password"),
+            Err(Error::InvalidKdfParams)
+        );
+        let mut modified = bytes.clone();
+        modified[10..14].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&modified, b"password"),
+            Err(Error::InvalidKdfParams)
+        );
+        let mut modified = bytes.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            KeyPackageStore::<C>::decrypt(&modified, b"password"),
+            Err(Error::DecryptionError)
+        );
+    }
+
+    let (nonces, _) = round1::commit(key_package.signing_share(), &mut rng);
+    let bytes = encrypt_signing_nonces(&nonces, b"password", &params, &mut rng).unwrap();
+    assert_eq!(decrypt_signing_nonces(&bytes, b"password").unwrap(), nonces);
+    assert_eq!(
+        decrypt_signing_nonces::<C>(&bytes, b"wrong password"),
+        Err(Error::DecryptionError)
+    );
+
+    assert_eq!(
+        KeyPackageStore::new(key_package, None, vec![]).encrypt(
+            b"password",
+            &KdfParams::new(0, 0, 0),
+            &mut rng
+        ),
+        Err(Error::InvalidKdfParams)
+    );
+    for params in [
+        KdfParams::new(MAX_KDF_M_COST + 1, 1, 1),
+        KdfParams::new(64, MAX_KDF_T_COST + 1, 1),
+    ] {
+        assert_eq!(
+            encrypt_signing_nonces(&nonces, b"password", &params, &mut rng),
+            Err(Error::InvalidKdfParams)
+        );
+    }
+}
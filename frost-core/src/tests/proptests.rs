@@ -71,6 +71,22 @@ where
         self.is_valid == self.vk.verify(&self.msg, &self.sig).is_ok()
     }
 
+    /// Check that batch verification of this case agrees with single
+    /// verification, both through [`VerifyingKey::verify`] and
+    /// [`batch::Item::verify_single`].
+    pub fn check_batch<R: RngCore + CryptoRng>(&self, rng: R) -> bool {
+        let single = self.vk.verify(&self.msg, &self.sig).is_ok();
+
+        let item: batch::Item<C> = (self.vk, self.sig, &self.msg).into();
+        let item_single = item.clone().verify_single().is_ok();
+
+        let mut batch = batch::Verifier::<C>::new();
+        batch.queue(item);
+        let batched = batch.verify(rng).is_ok();
+
+        single == item_single && single == batched
+    }
+
     /// Apply the given tweak to the signature test case.
     pub fn apply_tweak(&mut self, tweak: &Tweak) {
         match tweak {
@@ -97,3 +113,27 @@ pub fn tweak_strategy() -> impl Strategy<Value = Tweak> {
         1 => Just(Tweak::ChangePubkey),
     ]
 }
+
+/// Check that the Pippenger multiscalar multiplication agrees with the Straus
+/// implementation on `n` terms, a few of which use edge-case scalars.
+pub fn check_pippenger_matches_straus<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+    n: usize,
+) -> bool {
+    let zero = <<C::Group as Group>::Field>::zero();
+    let one = <<C::Group as Group>::Field>::one();
+    let scalars: Vec<_> = (0..n)
+        .map(|i| match i % 8 {
+            0 => zero - one,
+            1 => one,
+            2 => zero,
+            _ => <<C::Group as Group>::Field>::random(&mut rng),
+        })
+        .collect();
+    let elements: Vec<_> = (0..n)
+        .map(|_| C::Group::generator() * <<C::Group as Group>::Field>::random(&mut rng))
+        .collect();
+
+    crate::scalar_mul::pippenger_multiscalar_mul::<C::Group>(&scalars, &elements)
+        == crate::scalar_mul::straus_multiscalar_mul::<C::Group>(&scalars, &elements)
+}
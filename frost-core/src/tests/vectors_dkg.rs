@@ -11,7 +11,7 @@ use crate::{
             part2, part3, round1::Package as Round1Package, round1::SecretPackage,
             round2::Package as Round2Package,
         },
-        generate_secret_polynomial, KeyPackage, PublicKeyPackage, SigningShare,
+        generate_secret_polynomial, sum_commitments, KeyPackage, PublicKeyPackage, SigningShare,
         VerifiableSecretSharingCommitment, VerifyingShare,
     },
     Ciphersuite, Field, Group, Header, Identifier, Scalar, Signature, SigningKey, VerifyingKey,
@@ -157,6 +157,7 @@ fn build_public_key_package<C: Ciphersuite>(json_vectors: &Value) -> PublicKeyPa
     let mut verifying_shares = BTreeMap::new();
 
     let max_participants = json_vectors["config"]["MAX_PARTICIPANTS"].as_u64().unwrap() as u8;
+    let min_participants = json_vectors["config"]["MIN_PARTICIPANTS"].as_u64().unwrap() as u16;
 
     for i in 1..=max_participants {
         let participant_id: Identifier<C> = (inputs[i.to_string()]["identifier"].as_u64().unwrap()
@@ -173,10 +174,24 @@ fn build_public_key_package<C: Ciphersuite>(json_vectors: &Value) -> PublicKeyPa
     let verifying_key =
         VerifyingKey::deserialize(json_to_element::<C>(&inputs["verifying_key"])).unwrap();
 
+    // The group commitment is the sum of the commitments of all participants.
+    let round1_packages: Vec<_> = (1..=max_participants as usize)
+        .map(|i| build_round_1_package::<C>(json_vectors, i))
+        .collect();
+    let commitment = sum_commitments(
+        &round1_packages
+            .iter()
+            .map(|package| &package.commitment)
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+
     PublicKeyPackage {
         header: Header::default(),
         verifying_shares,
         verifying_key,
+        min_signers: Some(min_participants),
+        commitment: Some(commitment),
     }
 }
 
@@ -1,17 +1,21 @@
 //! Traits used to abstract Ciphersuites.
 
-use std::{
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
     fmt::Debug,
     ops::{Add, Mul, Sub},
 };
 
 use debugless_unwrap::DebuglessUnwrap;
 use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
 
 use crate::{
-    challenge,
-    keys::{KeyPackage, VerifyingShare},
-    round1, round2, BindingFactor, Challenge, Error, FieldError, GroupCommitment, GroupError,
+    batch, challenge,
+    keys::{KeyPackage, PublicKeyPackage, SecretShare, VerifyingShare},
+    round1, round2,
+    scalar_mul::VartimeMultiscalarMul,
+    BindingFactor, Challenge, Error, FieldError, GroupCommitment, GroupError, Identifier,
     Signature, SigningTarget, VerifyingKey,
 };
 
@@ -31,7 +35,9 @@ pub trait Field: Copy + Clone {
         + Eq
         + Mul<Output = Self::Scalar>
         + PartialEq
-        + Sub<Output = Self::Scalar>;
+        + Sub<Output = Self::Scalar>
+        + Send
+        + Sync;
 
     /// A unique byte array buf of fixed length N.
     type Serialization: AsRef<[u8]> + Debug + TryFrom<Vec<u8>>;
@@ -100,7 +106,9 @@ pub trait Group: Copy + Clone + PartialEq {
         + Eq
         + Mul<<Self::Field as Field>::Scalar, Output = Self::Element>
         + PartialEq
-        + Sub<Output = Self::Element>;
+        + Sub<Output = Self::Element>
+        + Send
+        + Sync;
 
     /// A unique byte array buf of fixed length N.
     ///
@@ -125,6 +133,37 @@ pub trait Group: Copy + Clone + PartialEq {
     /// [`ScalarBaseMult()`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-3.1-3.5
     fn generator() -> Self::Element;
 
+    /// Computes the scalar multiplication of the [`Group::generator()`] by
+    /// `scalar`.
+    ///
+    /// The scalar is often secret (e.g. a nonce or a signing share), so
+    /// implementations MUST run in constant time. The default implementation
+    /// computes `generator() * scalar`; ciphersuites should override it to use
+    /// the precomputed basepoint table of their curve crate if it provides one,
+    /// or a lazily built [`GeneratorTable`](crate::GeneratorTable) otherwise.
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        Self::generator() * *scalar
+    }
+
+    /// Computes the multiscalar multiplication
+    /// $$
+    /// Q = c\_1 E\_1 + \cdots + c\_n E\_n,
+    /// $$
+    /// using variable-time operations. `scalars` and `elements` MUST have the
+    /// same length.
+    ///
+    /// This is only ever called with public values (e.g. in batch verification
+    /// and when checking DKG packages). The default implementation uses a
+    /// generic NAF-based Straus algorithm; ciphersuites backed by a curve
+    /// library that provides a native multiscalar multiplication should
+    /// override it.
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        VartimeMultiscalarMul::<Self>::vartime_multiscalar_mul(scalars, elements)
+    }
+
     /// Check if element is odd
     #[allow(unused)]
     fn y_is_odd(element: &Self::Element) -> bool {
@@ -150,30 +189,75 @@ pub trait Group: Copy + Clone + PartialEq {
 /// An element of the [`Ciphersuite`] `C`'s [`Group`].
 pub type Element<C> = <<C as Ciphersuite>::Group as Group>::Element;
 
-/// This is a marker trait for types which are passed in to modify the signing logic of a [`Ciphersuite`].
+/// This is a trait for types which are passed in to modify the signing logic of a [`Ciphersuite`].
 ///
 /// If the `serde` feature is enabled, any type implementing this trait must also implement
 /// [`serde::Serialize`] and [`serde::Deserialize`].
 #[cfg(feature = "serde")]
 pub trait SigningParameters:
-    Clone + Debug + Eq + PartialEq + Default + serde::Serialize + for<'d> serde::Deserialize<'d>
+    Clone
+    + Debug
+    + Eq
+    + PartialEq
+    + Default
+    + Send
+    + Sync
+    + serde::Serialize
+    + for<'d> serde::Deserialize<'d>
 {
+    /// Encodes the parameters as they appear in the byte-oriented encoding of
+    /// a [`SigningPackage`](crate::SigningPackage).
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decodes parameters encoded with [`SigningParameters::to_bytes()`],
+    /// returning `None` if `bytes` is not a valid encoding.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+
+    /// Whether `message` can be signed with these parameters, e.g. whether
+    /// it has the length of the prehash a variant signs. Accepts any message
+    /// by default.
+    fn accepts_message(&self, _message: &[u8]) -> bool {
+        true
+    }
 }
 
-/// This is a marker trait for types which are passed in to modify the signing logic of a [`Ciphersuite`].
+/// This is a trait for types which are passed in to modify the signing logic of a [`Ciphersuite`].
 ///
 /// If the `serde` feature is enabled, any type implementing this trait must also implement
 /// [`serde::Serialize`] and [`serde::Deserialize`].
 #[cfg(not(feature = "serde"))]
-pub trait SigningParameters: Clone + Debug + Eq + PartialEq + Default {}
+pub trait SigningParameters: Clone + Debug + Eq + PartialEq + Default + Send + Sync {
+    /// Encodes the parameters as they appear in the byte-oriented encoding of
+    /// a [`SigningPackage`](crate::SigningPackage).
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Decodes parameters encoded with [`SigningParameters::to_bytes()`],
+    /// returning `None` if `bytes` is not a valid encoding.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+
+    /// Whether `message` can be signed with these parameters, e.g. whether
+    /// it has the length of the prehash a variant signs. Accepts any message
+    /// by default.
+    fn accepts_message(&self, _message: &[u8]) -> bool {
+        true
+    }
+}
 
-impl SigningParameters for () {}
+impl SigningParameters for () {
+    fn to_bytes(&self) -> Vec<u8> {
+        Vec::new()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.is_empty().then_some(())
+    }
+}
 
 /// A [FROST ciphersuite] specifies the underlying prime-order group details and cryptographic hash
 /// function.
 ///
 /// [FROST ciphersuite]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-ciphersuites
-pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
+pub trait Ciphersuite: Copy + Clone + PartialEq + Debug + Send + Sync {
     /// The ciphersuite ID string. It should be equal to the contextString in
     /// the spec. For new ciphersuites, this should be a string that identifies
     /// the ciphersuite; it's recommended to use a similar format to the
@@ -194,6 +278,17 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
     /// to produce an effective signature. Most ciphersuites will just set this to `()`.
     type SigningParameters: SigningParameters;
 
+    /// The validation rules a signature can be verified under (see
+    /// [`VerifyingKey::verify_with_mode()`] and
+    /// [`batch::Verifier::with_mode()`]), for ciphersuites whose implementations
+    /// disagree on which signatures are valid. Most ciphersuites will just set
+    /// this to `()`.
+    ///
+    /// The default mode must accept the same signatures as
+    /// [`VerifyingKey::verify()`] among those whose verifying key and signature
+    /// can be deserialized.
+    type VerificationMode: Copy + Clone + Debug + Default + Eq + PartialEq;
+
     /// [H1] for a FROST ciphersuite.
     ///
     /// Maps arbitrary inputs to `Self::Scalar` elements of the prime-order group scalar field.
@@ -257,9 +352,10 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
     /// # Cryptographic Safety
     ///
     /// You may override this to provide a tailored implementation, but if the ciphersuite defines it,
-    /// it must also multiply by the cofactor to comply with the RFC. Note that batch verification
-    /// (see [`crate::batch::Verifier`]) also uses the default implementation regardless whether a
-    /// tailored implementation was provided.
+    /// it must also multiply by the cofactor to comply with the RFC. If you do, you must also
+    /// override [`Ciphersuite::verify_item()`] and [`Ciphersuite::verify_batch()`] so that
+    /// verification of batch items (see [`crate::batch::Verifier`]) accepts exactly the same
+    /// signatures.
     fn verify_signature(
         sig_target: &SigningTarget<Self>,
         signature: &Signature<Self>,
@@ -270,6 +366,62 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
         public_key.verify_prehashed(c, signature, &sig_target.sig_params)
     }
 
+    /// Decode a [`batch::Item`] for the encoded `signature` over `sig_target`
+    /// made by the encoded `verifying_key`, which can then be verified under any
+    /// [`Ciphersuite::VerificationMode`].
+    ///
+    /// The default implementation deserializes them with
+    /// [`VerifyingKey::deserialize()`] and [`Signature::deserialize()`]. If a
+    /// mode accepts encodings that those reject, you must override it to decode
+    /// them, and check the encoding rules of each mode in
+    /// [`Ciphersuite::verify_item()`] and [`Ciphersuite::verify_batch()`].
+    fn decode_item(
+        verifying_key: <Self::Group as Group>::Serialization,
+        signature: Self::SignatureSerialization,
+        sig_target: SigningTarget<Self>,
+    ) -> Result<batch::Item<Self>, Error<Self>> {
+        Ok(batch::Item::new(
+            VerifyingKey::deserialize(verifying_key)?,
+            Signature::deserialize(signature)?,
+            sig_target,
+        ))
+    }
+
+    /// Verify the signature of a single [`batch::Item`] under `mode`.
+    ///
+    /// The default implementation ignores the mode and agrees with the default
+    /// implementation of [`Ciphersuite::verify_signature()`].
+    fn verify_item(
+        _mode: Self::VerificationMode,
+        item: &batch::Item<Self>,
+    ) -> Result<(), Error<Self>> {
+        item.verifying_key().verify_prehashed(
+            item.challenge().clone(),
+            item.signature(),
+            item.sig_params(),
+        )
+    }
+
+    /// Verify a batch of signatures for this ciphersuite under `mode`, returning `Ok(())` if all
+    /// of them are valid. `rng` is used to generate the random coefficients of the batch equation.
+    ///
+    /// The default implementation ignores the mode and uses the "cofactored" batch verification
+    /// equation described in [`crate::batch::Verifier::verify()`], which agrees with the default
+    /// implementation of [`Ciphersuite::verify_item()`].
+    ///
+    /// # Cryptographic Safety
+    ///
+    /// If you override this to use a tailored batch equation, it must accept a batch if and only
+    /// if [`Ciphersuite::verify_item()`] accepts each of its signatures under `mode` (except with
+    /// negligible probability).
+    fn verify_batch<R: RngCore + CryptoRng>(
+        _mode: Self::VerificationMode,
+        items: &[batch::Item<Self>],
+        rng: &mut R,
+    ) -> Result<(), Error<Self>> {
+        batch::verify_cofactored(items, rng)
+    }
+
     /// Generates the challenge as is required for Schnorr signatures.
     ///
     /// Deals in bytes, so that [FROST] and singleton signing and verification can use it with different
@@ -287,6 +439,54 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
         challenge(R, verifying_key, &sig_target.message)
     }
 
+    /// Derives the nonce of a deterministic single-signer signature (see
+    /// [`SigningKey::sign_deterministic()`](crate::SigningKey::sign_deterministic))
+    /// from the effective secret key (see [`Ciphersuite::effective_secret_key()`]),
+    /// the verifying key, the auxiliary data `aux` (which is empty unless given
+    /// to [`SigningKey::sign_deterministic_with_aux()`](crate::SigningKey::sign_deterministic_with_aux))
+    /// and the signing target.
+    ///
+    /// The default implementation hashes them with H3, which is also used to
+    /// derive the FROST signing nonces. Ciphersuites with a standard
+    /// deterministic nonce derivation (e.g. RFC 8032) should override it.
+    ///
+    /// # Cryptographic Safety
+    ///
+    /// The nonce must be secret and different for every signing target, so it
+    /// must be derived from the secret key and everything the challenge is
+    /// derived from.
+    fn deterministic_nonce(
+        secret: &<<Self::Group as Group>::Field as Field>::Scalar,
+        verifying_key: &VerifyingKey<Self>,
+        aux: &[u8],
+        sig_target: &SigningTarget<Self>,
+    ) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        let sig_params = sig_target.sig_params.to_bytes();
+        let secret = <Self::Group as Group>::Field::serialize(secret);
+        let verifying_key = verifying_key.serialize();
+        // Allocate the preimage at once, so that it is not reallocated and
+        // no copy of the secret is left behind when it is zeroized.
+        let len = secret.as_ref().len()
+            + verifying_key.as_ref().len()
+            + 8
+            + aux.len()
+            + 8
+            + sig_params.len()
+            + sig_target.message.len();
+        let mut preimage = Zeroizing::new(Vec::with_capacity(len));
+        preimage.extend_from_slice(secret.as_ref());
+        preimage.extend_from_slice(verifying_key.as_ref());
+        // The variable-length inputs are prefixed with their length, so that
+        // they can not be shifted into each other.
+        for input in [aux, &sig_params[..]] {
+            preimage.extend_from_slice(&(input.len() as u64).to_be_bytes());
+            preimage.extend_from_slice(input);
+        }
+        preimage.extend_from_slice(&sig_target.message);
+        debug_assert_eq!(preimage.len(), len);
+        Self::H3(&preimage)
+    }
+
     /// Finalize an aggregated group signature. This is used by frost-sepc256k1-tr
     /// to ensure the signature is valid under BIP340.
     fn aggregate_sig_finalize(
@@ -453,4 +653,37 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug {
     ) -> <Self::Group as Group>::Element {
         verifying_share.0
     }
+
+    /// Post-process the output of trusted dealer key generation, i.e. of
+    /// [`keys::generate_with_dealer()`](crate::keys::generate_with_dealer) and
+    /// [`keys::split()`](crate::keys::split).
+    ///
+    /// In frost-redpallas, this negates the secret shares and the public key
+    /// package if the group verifying key has an odd Y coordinate, since
+    /// Orchard requires spend validating keys with an even one.
+    #[allow(clippy::type_complexity)]
+    fn post_generate(
+        secret_shares: BTreeMap<Identifier<Self>, SecretShare<Self>>,
+        public_key_package: PublicKeyPackage<Self>,
+    ) -> Result<
+        (
+            BTreeMap<Identifier<Self>, SecretShare<Self>>,
+            PublicKeyPackage<Self>,
+        ),
+        Error<Self>,
+    > {
+        Ok((secret_shares, public_key_package))
+    }
+
+    /// Post-process the output of the DKG for a participant, i.e. of
+    /// [`keys::dkg::part3()`](crate::keys::dkg::part3).
+    ///
+    /// In frost-redpallas, this negates the key package and the public key
+    /// package if the group verifying key has an odd Y coordinate.
+    fn post_dkg(
+        key_package: KeyPackage<Self>,
+        public_key_package: PublicKeyPackage<Self>,
+    ) -> Result<(KeyPackage<Self>, PublicKeyPackage<Self>), Error<Self>> {
+        Ok((key_package, public_key_package))
+    }
 }
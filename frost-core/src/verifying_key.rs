@@ -1,5 +1,5 @@
+use core::fmt::{self, Debug};
 use derive_getters::Getters;
-use std::fmt::{self, Debug};
 
 #[cfg(any(test, feature = "test-impl"))]
 use hex::FromHex;
@@ -66,6 +66,8 @@ where
 
     /// Verify a purported `signature` with a pre-hashed [`Challenge`] made by this verification
     /// key.
+    #[cfg_attr(feature = "internals", visibility::make(pub))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
     pub(crate) fn verify_prehashed(
         &self,
         challenge: Challenge<C>,
@@ -76,10 +78,10 @@ where
         //                 h * ( z * B - c * A - R) == 0
         //
         // where h is the cofactor
-        let R = signature.R;
+        let R = C::effective_nonce_element(signature.R);
         let vk = C::effective_pubkey_element(&self, sig_params);
 
-        let zB = C::Group::generator() * signature.z;
+        let zB = C::Group::generator_mul(&signature.z);
         let cA = vk * challenge.0;
         let check = (zB - cA - R) * C::Group::cofactor();
 
@@ -99,6 +101,22 @@ where
         C::verify_signature(&sig_target.into(), signature, self)
     }
 
+    /// Verify the encoded `signature` over `sig_target` made by this verification key, under
+    /// the given ciphersuite-specific validation rules.
+    ///
+    /// This takes the encoded signature since some modes accept signatures that
+    /// [`Signature::deserialize`] rejects; see [`crate::batch::Item::from_encodings`] to also
+    /// verify encoded verifying keys that [`VerifyingKey::deserialize`] rejects.
+    pub fn verify_with_mode(
+        &self,
+        mode: C::VerificationMode,
+        sig_target: impl Into<SigningTarget<C>>,
+        signature: C::SignatureSerialization,
+    ) -> Result<(), Error<C>> {
+        crate::batch::Item::from_encodings(self.serialize(), signature, sig_target)?
+            .verify_single_with_mode(mode)
+    }
+
     /// Computes the group public key given the group commitment.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn from_commitment(
@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::thread_rng;
+
+use frost_decaf448::*;
+
+fn bench_decaf448_batch_verify(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify::<Decaf448Shake256, _>(c, "decaf448", &mut rng);
+}
+
+fn bench_decaf448_sign(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_sign::<Decaf448Shake256, _>(c, "decaf448", &mut rng);
+}
+
+criterion_group!(benches, bench_decaf448_batch_verify, bench_decaf448_sign);
+criterion_main!(benches);
@@ -0,0 +1,466 @@
+#![allow(non_snake_case)]
+#![deny(missing_docs)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc = document_features::document_features!()]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use ed448_goldilocks_plus::{CompressedDecaf, DecafPoint, Scalar, ScalarBytes, WideScalarBytes};
+use frost_rerandomized::RandomizedCiphersuite;
+use rand_core::{CryptoRng, RngCore};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+use frost_core::{self as frost, GeneratorTable};
+
+#[cfg(test)]
+mod tests;
+
+// Re-exports in our public API
+pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError};
+pub use rand_core;
+
+/// An error.
+pub type Error = frost_core::Error<Decaf448Shake256>;
+
+/// An implementation of the FROST(decaf448, SHAKE256) ciphersuite scalar field.
+#[derive(Clone, Copy)]
+pub struct Decaf448ScalarField;
+
+impl Field for Decaf448ScalarField {
+    type Scalar = Scalar;
+
+    type Serialization = [u8; 56];
+
+    fn zero() -> Self::Scalar {
+        Scalar::ZERO
+    }
+
+    fn one() -> Self::Scalar {
+        Scalar::ONE
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError> {
+        if *scalar == <Self as Field>::zero() {
+            Err(FieldError::InvalidZeroScalar)
+        } else {
+            Ok(scalar.invert())
+        }
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Scalar {
+        Scalar::random(rng)
+    }
+
+    fn serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        scalar.to_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Scalar, FieldError> {
+        // Scalars are encoded in 56 bytes, unlike the 57 bytes of RFC 8032
+        // which `from_canonical_bytes()` expects.
+        let mut bytes = ScalarBytes::default();
+        bytes[..56].copy_from_slice(buf);
+        match Scalar::from_canonical_bytes(&bytes).into() {
+            Some(s) => Ok(s),
+            None => Err(FieldError::MalformedScalar),
+        }
+    }
+
+    fn little_endian_serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        Self::serialize(scalar)
+    }
+}
+
+/// An implementation of the FROST(decaf448, SHAKE256) ciphersuite group.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Decaf448Group;
+
+impl Group for Decaf448Group {
+    type Field = Decaf448ScalarField;
+
+    type Element = DecafPoint;
+
+    /// The [RFC 9496] encoding of a decaf448 element.
+    ///
+    /// [RFC 9496]: https://www.rfc-editor.org/rfc/rfc9496.html#section-5.3.2
+    type Serialization = [u8; 56];
+
+    fn cofactor() -> <Self::Field as Field>::Scalar {
+        Scalar::ONE
+    }
+
+    fn identity() -> Self::Element {
+        DecafPoint::IDENTITY
+    }
+
+    fn generator() -> Self::Element {
+        DecafPoint::GENERATOR
+    }
+
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        static TABLE: OnceLock<GeneratorTable<Decaf448Group>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::new).mul(scalar)
+    }
+
+    fn serialize(element: &Self::Element) -> Self::Serialization {
+        element.compress().0
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Element, GroupError> {
+        // `decompress()` rejects non-canonical encodings.
+        match Option::<DecafPoint>::from(CompressedDecaf(*buf).decompress()) {
+            Some(point) => {
+                if point == Self::identity() {
+                    Err(GroupError::InvalidIdentityElement)
+                } else {
+                    Ok(point)
+                }
+            }
+            None => Err(GroupError::MalformedElement),
+        }
+    }
+}
+
+fn hash_to_array(inputs: &[&[u8]]) -> [u8; 114] {
+    let mut h = Shake256::default();
+    for i in inputs {
+        h.update(i);
+    }
+    let mut reader = h.finalize_xof();
+    let mut output = [0u8; 114];
+    reader.read(&mut output);
+    output
+}
+
+fn hash_to_scalar(inputs: &[&[u8]]) -> Scalar {
+    let mut output = WideScalarBytes::default();
+    output.copy_from_slice(&hash_to_array(inputs));
+    Scalar::from_bytes_mod_order_wide(&output)
+}
+
+/// Context string of the ciphersuite, following the naming of the
+/// ciphersuites in the [spec].
+///
+/// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.3-1
+const CONTEXT_STRING: &str = "FROST-DECAF448-SHAKE256-v1";
+
+/// An implementation of the FROST(decaf448, SHAKE256) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Decaf448Shake256;
+
+/// The ciphersuite-specific signing parameters which are fed into
+/// signing code to ensure correctly compliant signatures are computed.
+pub type SigningParameters = ();
+
+/// The message target which the group's signature should commit to. Includes
+/// a message byte vector, and a set of ciphersuite-specific parameters.
+pub type SigningTarget = frost_core::SigningTarget<Decaf448Shake256>;
+
+impl Ciphersuite for Decaf448Shake256 {
+    const ID: &'static str = CONTEXT_STRING;
+
+    type Group = Decaf448Group;
+
+    type HashOutput = [u8; 114];
+
+    type SignatureSerialization = [u8; 112];
+
+    type SigningParameters = SigningParameters;
+
+    type VerificationMode = ();
+
+    /// H1 for FROST(decaf448, SHAKE256)
+    fn H1(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"rho", m])
+    }
+
+    /// H2 for FROST(decaf448, SHAKE256)
+    ///
+    /// Unlike FROST(Ed448, SHAKE256), this does not need to be compatible
+    /// with RFC 8032, so it is domain separated like the other hashes.
+    fn H2(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"chal", m])
+    }
+
+    /// H3 for FROST(decaf448, SHAKE256)
+    fn H3(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"nonce", m])
+    }
+
+    /// H4 for FROST(decaf448, SHAKE256)
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"msg", m])
+    }
+
+    /// H5 for FROST(decaf448, SHAKE256)
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"com", m])
+    }
+
+    /// HDKG for FROST(decaf448, SHAKE256)
+    fn HDKG(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"dkg", m]))
+    }
+
+    /// HID for FROST(decaf448, SHAKE256)
+    fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
+    }
+}
+
+impl RandomizedCiphersuite for Decaf448Shake256 {
+    fn hash_randomizer(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[
+            CONTEXT_STRING.as_bytes(),
+            b"randomizer",
+            m,
+        ]))
+    }
+}
+
+// Shorthand alias for the ciphersuite
+type D = Decaf448Shake256;
+
+/// A FROST(decaf448, SHAKE256) participant identifier.
+pub type Identifier = frost::Identifier<D>;
+/// FROST(decaf448, SHAKE256) keys, key generation, key shares.
+pub mod keys {
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+
+    /// The identifier list to use when generating key shares.
+    pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, D>;
+
+    /// Allows all participants' keys to be generated using a central, trusted
+    /// dealer.
+    pub fn generate_with_dealer<RNG: RngCore + CryptoRng>(
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        mut rng: RNG,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, &mut rng)
+    }
+
+    /// Splits an existing key into FROST shares.
+    ///
+    /// This is identical to [`generate_with_dealer`] but receives an existing key
+    /// instead of generating a fresh one. This is useful in scenarios where
+    /// the key needs to be generated externally or must be derived from e.g. a
+    /// seed phrase.
+    pub fn split<R: RngCore + CryptoRng>(
+        secret: &SigningKey,
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        rng: &mut R,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
+    }
+
+    /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
+    ///
+    /// This can be used if for some reason the original key must be restored; e.g.
+    /// if threshold signing is not required anymore.
+    ///
+    /// This is NOT required to sign with FROST; the whole point of FROST is being
+    /// able to generate signatures only using the shares, without having to
+    /// reconstruct the original key.
+    ///
+    /// The caller is responsible for providing at least `min_signers` shares;
+    /// if less than that is provided, a different key will be returned.
+    pub fn reconstruct(secret_shares: &[KeyPackage]) -> Result<SigningKey, Error> {
+        frost::keys::reconstruct(secret_shares)
+    }
+
+    /// Secret and public key material generated by a dealer performing
+    /// [`generate_with_dealer`].
+    ///
+    /// # Security
+    ///
+    /// To derive a FROST(decaf448, SHAKE256) keypair, the receiver of the [`SecretShare`] *must* call
+    /// .into(), which under the hood also performs validation.
+    pub type SecretShare = frost::keys::SecretShare<D>;
+
+    /// A secret scalar value representing a signer's share of the group secret.
+    pub type SigningShare = frost::keys::SigningShare<D>;
+
+    /// A public group element that represents a single signer's public verification share.
+    pub type VerifyingShare = frost::keys::VerifyingShare<D>;
+
+    /// A FROST(decaf448, SHAKE256) keypair, which can be generated either by a trusted dealer or using
+    /// a DKG.
+    ///
+    /// When using a central dealer, [`SecretShare`]s are distributed to
+    /// participants, who then perform verification, before deriving
+    /// [`KeyPackage`]s, which they store to later use during signing.
+    pub type KeyPackage = frost::keys::KeyPackage<D>;
+
+    /// Public data that contains all the signers' public keys as well as the
+    /// group public key.
+    ///
+    /// Used for verification purposes before publishing a signature.
+    pub type PublicKeyPackage = frost::keys::PublicKeyPackage<D>;
+
+    /// Contains the commitments to the coefficients for our secret polynomial _f_,
+    /// used to generate participants' key shares.
+    ///
+    /// [`VerifiableSecretSharingCommitment`] contains a set of commitments to the coefficients (which
+    /// themselves are scalars) for a secret polynomial f, where f is used to
+    /// generate each ith participant's key share f(i). Participants use this set of
+    /// commitments to perform verifiable secret sharing.
+    ///
+    /// Note that participants MUST be assured that they have the *same*
+    /// [`VerifiableSecretSharingCommitment`], either by performing pairwise comparison, or by using
+    /// some agreed-upon public location for publication, where each participant can
+    /// ensure that they received the correct (and same) value.
+    pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<D>;
+
+    /// A description of a FROST(decaf448, SHAKE256) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<D>;
+
+    pub mod dkg;
+    pub mod repairable;
+}
+
+/// FROST(decaf448, SHAKE256) Round 1 functionality and types.
+pub mod round1 {
+    use crate::keys::SigningShare;
+
+    use super::*;
+
+    /// Comprised of FROST(decaf448, SHAKE256) hiding and binding nonces.
+    ///
+    /// Note that [`SigningNonces`] must be used *only once* for a signing
+    /// operation; re-using nonces will result in leakage of a signer's long-lived
+    /// signing key.
+    pub type SigningNonces = frost::round1::SigningNonces<D>;
+
+    /// Published by each participant in the first round of the signing protocol.
+    ///
+    /// This step can be batched if desired by the implementation. Each
+    /// SigningCommitment can be used for exactly *one* signature.
+    pub type SigningCommitments = frost::round1::SigningCommitments<D>;
+
+    /// A commitment to a signing nonce share.
+    pub type NonceCommitment = frost::round1::NonceCommitment<D>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Generates the signing nonces and commitments to be used in the signing
+    /// operation.
+    pub fn commit<RNG>(secret: &SigningShare, rng: &mut RNG) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit::<D, RNG>(secret, rng)
+    }
+}
+
+/// Generated by the coordinator of the signing operation and distributed to
+/// each signing party.
+pub type SigningPackage = frost::SigningPackage<D>;
+
+/// FROST(decaf448, SHAKE256) Round 2 functionality and types, for signature share generation.
+pub mod round2 {
+    use super::*;
+
+    /// A FROST(decaf448, SHAKE256) participant's signature share, which the Coordinator will aggregate with all other signer's
+    /// shares into the joint signature.
+    pub type SignatureShare = frost::round2::SignatureShare<D>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Receives the message to be signed and a set of signing commitments and a set
+    /// of randomizing commitments to be used in that signing operation, including
+    /// that for this participant.
+    ///
+    /// Assumes the participant has already determined which nonce corresponds with
+    /// the commitment that was assigned by the coordinator in the SigningPackage.
+    pub fn sign(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign(signing_package, signer_nonces, key_package)
+    }
+}
+
+/// FROST(decaf448, SHAKE256) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(decaf448, SHAKE256) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<D>;
+
+    /// Encrypt FROST(decaf448, SHAKE256) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(decaf448, SHAKE256) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
+/// A Schnorr signature on FROST(decaf448, SHAKE256).
+pub type Signature = frost_core::Signature<D>;
+
+/// Verifies each FROST(decaf448, SHAKE256) participant's signature share, and if all are valid,
+/// aggregates the shares into a signature to publish.
+///
+/// Resulting signature is compatible with verification of a plain Schnorr
+/// signature.
+///
+/// This operation is performed by a coordinator that can communicate with all
+/// the signing participants before publishing the final signature. The
+/// coordinator can be one of the participants or a semi-trusted third party
+/// (who is trusted to not perform denial of service attacks, but does not learn
+/// any secret information). Note that because the coordinator is trusted to
+/// report misbehaving parties in order to avoid publishing an invalid
+/// signature, if the coordinator themselves is a signer and misbehaves, they
+/// can avoid that step. However, at worst, this results in a denial of
+/// service attack due to publishing an invalid signature.
+pub fn aggregate(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate(signing_package, signature_shares, pubkeys)
+}
+
+/// A signing key for a Schnorr signature on FROST(decaf448, SHAKE256).
+pub type SigningKey = frost_core::SigningKey<D>;
+
+/// A valid verifying key for Schnorr signatures on FROST(decaf448, SHAKE256).
+pub type VerifyingKey = frost_core::VerifyingKey<D>;
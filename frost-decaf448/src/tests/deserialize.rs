@@ -0,0 +1,66 @@
+use crate::*;
+
+#[test]
+fn check_deserialize_non_canonical() {
+    let encoded_generator = <Decaf448Shake256 as Ciphersuite>::Group::serialize(
+        &<Decaf448Shake256 as Ciphersuite>::Group::generator(),
+    );
+
+    let r = <Decaf448Shake256 as Ciphersuite>::Group::deserialize(&encoded_generator);
+    assert!(r.is_ok());
+
+    // This is s = p, which is non-canonical.
+    let encoded_point = hex::decode(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    )
+    .unwrap()
+    .try_into()
+    .unwrap();
+    let r = <Decaf448Shake256 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+
+    // This is s = 1, which is negative.
+    let mut encoded_point = [0u8; 56];
+    encoded_point[0] = 1;
+    let r = <Decaf448Shake256 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+}
+
+#[test]
+fn check_deserialize_identity() {
+    let encoded_identity = [0u8; 56];
+
+    let r = <Decaf448Shake256 as Ciphersuite>::Group::deserialize(&encoded_identity);
+    assert_eq!(r, Err(GroupError::InvalidIdentityElement));
+}
+
+/// Check the encodings of the first multiples of the generator from
+/// [RFC 9496, Appendix A.2][rfc].
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc9496.html#appendix-A.2
+#[test]
+fn check_generator_multiples() {
+    let multiples = [
+        "6666666666666666666666666666666666666666666666666666666633333333333333333333333333333333333333333333333333333333",
+        "c898eb4f87f97c564c6fd61fc7e49689314a1f818ec85eeb3bd5514ac816d38778f69ef347a89fca817e66defdedce178c7cc709b2116e75",
+        "a0c09bf2ba7208fda0f4bfe3d0f5b29a543012306d43831b5adc6fe7f8596fa308763db15468323b11cf6e4aeb8c18fe44678f44545a69bc",
+    ];
+
+    let generator = <Decaf448Shake256 as Ciphersuite>::Group::generator();
+    let mut element = generator;
+    for multiple in multiples {
+        assert_eq!(
+            hex::encode(<Decaf448Shake256 as Ciphersuite>::Group::serialize(
+                &element
+            )),
+            multiple
+        );
+        assert_eq!(
+            <Decaf448Shake256 as Ciphersuite>::Group::deserialize(
+                &hex::decode(multiple).unwrap().try_into().unwrap()
+            ),
+            Ok(element)
+        );
+        element += generator;
+    }
+}
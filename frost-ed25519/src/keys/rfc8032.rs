@@ -0,0 +1,164 @@
+//! Import of existing [RFC 8032] Ed25519 keys.
+//!
+//! [`SigningKey`] holds the secret scalar directly, while RFC 8032 keys are
+//! usually stored as a 32-byte seed, or as its expanded secret key: the
+//! SHA-512 hash of the seed, whose first half is clamped into the secret
+//! scalar. The functions in this module derive the same secret scalar as
+//! RFC 8032, so the imported key (and the group key of the shares it is
+//! split into) has the same public key.
+//!
+//! The second half of the expanded secret key is the prefix RFC 8032 derives
+//! deterministic nonces from. [`SigningKey`] does not keep it, since FROST
+//! signatures do not use it; [`ExpandedSecretKey`] keeps it along with the
+//! secret scalar, and signs with the same deterministic nonces as RFC 8032.
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.5
+
+use alloc::collections::BTreeMap;
+
+use curve25519_dalek::scalar::Scalar;
+use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
+
+use frost_core::SigningParameters as _;
+
+use crate::{
+    hash_to_scalar, CryptoRng, Error, Identifier, RngCore, Signature, SigningKey, SigningTarget,
+    VerifyingKey,
+};
+
+use super::{IdentifierList, PublicKeyPackage, SecretShare};
+
+/// An RFC 8032 expanded secret key: the secret scalar, as a [`SigningKey`],
+/// and the prefix RFC 8032 derives deterministic nonces from.
+#[derive(Clone)]
+pub struct ExpandedSecretKey {
+    signing_key: SigningKey,
+    prefix: Zeroizing<[u8; 32]>,
+}
+
+impl ExpandedSecretKey {
+    /// Expand an RFC 8032 seed (the 32-byte Ed25519 secret key).
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        let mut expanded_secret_key = Zeroizing::new([0u8; 64]);
+        Sha512::new()
+            .chain_update(seed)
+            .finalize_into((&mut *expanded_secret_key).into());
+        Self::from_bytes(&expanded_secret_key)
+    }
+
+    /// Import an RFC 8032 expanded secret key (the SHA-512 hash of the seed).
+    pub fn from_bytes(expanded_secret_key: &[u8; 64]) -> Self {
+        // Clamp the first half as in RFC 8032.
+        let mut scalar_bytes = Zeroizing::new([0u8; 32]);
+        scalar_bytes.copy_from_slice(&expanded_secret_key[..32]);
+        scalar_bytes[0] &= 0xf8;
+        scalar_bytes[31] &= 0x7f;
+        scalar_bytes[31] |= 0x40;
+
+        let mut prefix = Zeroizing::new([0u8; 32]);
+        prefix.copy_from_slice(&expanded_secret_key[32..]);
+
+        Self {
+            signing_key: SigningKey::from_scalar(Scalar::from_bytes_mod_order(*scalar_bytes)),
+            prefix,
+        }
+    }
+
+    /// Return the [`SigningKey`] holding the secret scalar.
+    pub fn signing_key(&self) -> SigningKey {
+        self.signing_key
+    }
+
+    /// Return the public key.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.signing_key.into()
+    }
+
+    /// Create a signature on `sig_target` as RFC 8032 does, with the nonce
+    /// `SHA-512(dom2 || prefix || M)`, where `M` is the message of the
+    /// signing target (the prehash for Ed25519ph). The signature is the same
+    /// as the one other RFC 8032 implementations make with the same key.
+    ///
+    /// Returns [`Error::InvalidSigningParameters`] if the signing parameters
+    /// do not accept the message.
+    pub fn sign(&self, sig_target: impl Into<SigningTarget>) -> Result<Signature, Error> {
+        let sig_target = sig_target.into();
+        if !sig_target
+            .sig_params()
+            .accepts_message(sig_target.message())
+        {
+            return Err(Error::InvalidSigningParameters);
+        }
+        let r = hash_to_scalar(&[
+            &sig_target.sig_params().dom2(),
+            &self.prefix[..],
+            sig_target.message(),
+        ]);
+        Ok(self.signing_key.sign_with_nonce(r, &sig_target))
+    }
+}
+
+impl core::fmt::Debug for ExpandedSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ExpandedSecretKey")
+            .field(&"<redacted>")
+            .finish()
+    }
+}
+
+/// Import an RFC 8032 seed (the 32-byte Ed25519 secret key) as a
+/// [`SigningKey`] with the same public key.
+pub fn signing_key_from_seed(seed: &[u8; 32]) -> SigningKey {
+    ExpandedSecretKey::from_seed(seed).signing_key()
+}
+
+/// Import an RFC 8032 expanded secret key (the SHA-512 hash of the seed) as
+/// a [`SigningKey`] with the same public key.
+pub fn signing_key_from_expanded_secret_key(expanded_secret_key: &[u8; 64]) -> SigningKey {
+    ExpandedSecretKey::from_bytes(expanded_secret_key).signing_key()
+}
+
+/// Splits an existing RFC 8032 seed into FROST shares.
+///
+/// This is identical to [`split`](super::split) but receives the seed, which
+/// is imported with [`signing_key_from_seed`]. The group verifying key of the
+/// returned [`PublicKeyPackage`] is the public key of the seed.
+pub fn split_seed<R: RngCore + CryptoRng>(
+    seed: &[u8; 32],
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: IdentifierList,
+    rng: &mut R,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+    super::split(
+        &signing_key_from_seed(seed),
+        max_signers,
+        min_signers,
+        identifiers,
+        rng,
+    )
+}
+
+/// Splits an existing RFC 8032 expanded secret key into FROST shares.
+///
+/// This is identical to [`split`](super::split) but receives the expanded
+/// secret key, which is imported with
+/// [`signing_key_from_expanded_secret_key`]. The group verifying key of the
+/// returned [`PublicKeyPackage`] is the public key of the expanded secret
+/// key.
+pub fn split_expanded_secret_key<R: RngCore + CryptoRng>(
+    expanded_secret_key: &[u8; 64],
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: IdentifierList,
+    rng: &mut R,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+    super::split(
+        &signing_key_from_expanded_secret_key(expanded_secret_key),
+        max_signers,
+        min_signers,
+        identifiers,
+        rng,
+    )
+}
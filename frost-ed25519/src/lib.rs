@@ -4,27 +4,37 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
 
 use curve25519_dalek::{
     constants::ED25519_BASEPOINT_POINT,
     edwards::{CompressedEdwardsY, EdwardsPoint},
     scalar::Scalar,
-    traits::Identity,
+    traits::{Identity, VartimeMultiscalarMul},
 };
 use frost_rerandomized::RandomizedCiphersuite;
 use rand_core::{CryptoRng, RngCore};
 use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
 
 use frost_core as frost;
 
+mod signing_parameters;
 #[cfg(test)]
 mod tests;
+mod verification;
 
 // Re-exports in our public API
-pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError};
+pub use frost_core::{
+    serde, Challenge, Ciphersuite, Element, Field, FieldError, Group, GroupError,
+};
 pub use rand_core;
+pub use signing_parameters::{Ed25519Variant, SigningParameters};
+pub use verification::VerificationMode;
 
 /// An error.
 pub type Error = frost_core::Error<Ed25519Sha512>;
@@ -99,6 +109,17 @@ impl Group for Ed25519Group {
         ED25519_BASEPOINT_POINT
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        EdwardsPoint::mul_base(scalar)
+    }
+
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        EdwardsPoint::vartime_multiscalar_mul(scalars, elements)
+    }
+
     fn serialize(element: &Self::Element) -> Self::Serialization {
         element.compress().to_bytes()
     }
@@ -155,10 +176,6 @@ const CONTEXT_STRING: &str = "FROST-ED25519-SHA512-v1";
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Ed25519Sha512;
 
-/// The ciphersuite-specific signing parameters which are fed into
-/// signing code to ensure correctly compliant signatures are computed.
-pub type SigningParameters = ();
-
 /// The message target which the group's signature should commit to. Includes
 /// a message byte vector, and a set of ciphersuite-specific parameters.
 pub type SigningTarget = frost_core::SigningTarget<Ed25519Sha512>;
@@ -172,7 +189,9 @@ impl Ciphersuite for Ed25519Sha512 {
 
     type SignatureSerialization = [u8; 64];
 
-    type SigningParameters = ();
+    type SigningParameters = SigningParameters;
+
+    type VerificationMode = VerificationMode;
 
     /// H1 for FROST(Ed25519, SHA-512)
     ///
@@ -218,6 +237,76 @@ impl Ciphersuite for Ed25519Sha512 {
     fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
         Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
     }
+
+    /// Generates the challenge as is required for Schnorr signatures, prefixed
+    /// with `dom2` for the Ed25519ctx and Ed25519ph variants.
+    fn challenge(
+        R: &Element<E>,
+        verifying_key: &VerifyingKey,
+        sig_target: &SigningTarget,
+    ) -> Challenge<E> {
+        Challenge::from_scalar(hash_to_scalar(&[
+            &sig_target.sig_params().dom2(),
+            &R.compress().to_bytes(),
+            &verifying_key.serialize(),
+            sig_target.message(),
+        ]))
+    }
+
+    /// Decodes the verifying key and the signature as any [`VerificationMode`]
+    /// accepts them, and computes the challenge from their encodings as given.
+    fn decode_item(
+        verifying_key: [u8; 32],
+        signature: [u8; 64],
+        sig_target: SigningTarget,
+    ) -> Result<frost::batch::Item<E>, Error> {
+        verification::decode_item(verifying_key, signature, sig_target)
+    }
+
+    /// Verifies the signature of `item` under the rules of `mode`.
+    fn verify_item(mode: VerificationMode, item: &frost::batch::Item<E>) -> Result<(), Error> {
+        verification::verify_item(mode, item)
+    }
+
+    /// Verifies a batch of signatures under the rules of `mode`; only
+    /// [`VerificationMode::Zip215`] signatures are actually batched, strict
+    /// ones are verified one by one.
+    fn verify_batch<R: RngCore + CryptoRng>(
+        mode: VerificationMode,
+        items: &[frost::batch::Item<E>],
+        rng: &mut R,
+    ) -> Result<(), Error> {
+        verification::verify_batch(mode, items, rng)
+    }
+
+    /// Derives the nonce as in RFC 8032, `SHA-512(dom2 || prefix || M)`,
+    /// where `M` is the message of the signing target (the prehash for
+    /// Ed25519ph).
+    ///
+    /// Since the signing key is a scalar rather than an RFC 8032 seed, the
+    /// 32-byte `prefix` is the first half of
+    /// `SHA-512(CONTEXT_STRING || "prefix" || secret || aux)`. Keys imported
+    /// from a seed sign with the prefix of the seed with
+    /// [`keys::rfc8032::ExpandedSecretKey::sign()`] instead, which gives the
+    /// same signatures as other RFC 8032 implementations.
+    fn deterministic_nonce(
+        secret: &Scalar,
+        _verifying_key: &VerifyingKey,
+        aux: &[u8],
+        sig_target: &SigningTarget,
+    ) -> Scalar {
+        let prefix = Zeroizing::new(hash_to_array(&[
+            CONTEXT_STRING.as_bytes(),
+            b"prefix",
+            secret.as_bytes(),
+            aux,
+        ]));
+        hash_to_scalar(&[
+            &sig_target.sig_params().dom2(),
+            &prefix[..32],
+            sig_target.message(),
+        ])
+    }
 }
 
 impl RandomizedCiphersuite for Ed25519Sha512 {
@@ -237,7 +326,7 @@ pub type Identifier = frost::Identifier<E>;
 
 /// FROST(Ed25519, SHA-512) keys, key generation, key shares.
 pub mod keys {
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     use super::*;
 
@@ -329,8 +418,13 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<E>;
 
+    /// A description of a FROST(Ed25519, SHA-512) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<E>;
+
     pub mod dkg;
     pub mod repairable;
+    pub mod rfc8032;
 }
 
 /// FROST(Ed25519, SHA-512) Round 1 functionality and types.
@@ -396,6 +490,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(Ed25519, SHA-512) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(Ed25519, SHA-512) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<E>;
+
+    /// Encrypt FROST(Ed25519, SHA-512) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(Ed25519, SHA-512) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(Ed25519, SHA-512).
 pub type Signature = frost_core::Signature<E>;
 
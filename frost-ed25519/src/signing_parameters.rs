@@ -0,0 +1,182 @@
+//! The signing parameters of the Ed25519 variants from [RFC 8032].
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+
+use alloc::vec::Vec;
+
+use sha2::{Digest, Sha512};
+
+use crate::{Error, SigningTarget};
+
+/// The prefix of `dom2` in [RFC 8032], which is hashed before the rest of the
+/// challenge in the Ed25519ctx and Ed25519ph variants.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// The length of the SHA-512 prehash signed by Ed25519ph.
+const PREHASH_LEN: usize = 64;
+
+/// The variant of Ed25519 from [RFC 8032] that signatures are produced for.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ed25519Variant {
+    /// Plain Ed25519, which hashes `R || A || M`.
+    #[default]
+    Ed25519,
+    /// Ed25519ctx, which prefixes the challenge with `dom2(0, context)`.
+    Ed25519ctx,
+    /// Ed25519ph, which prefixes the challenge with `dom2(1, context)` and
+    /// signs the SHA-512 prehash of the message.
+    Ed25519ph,
+}
+
+/// The ciphersuite-specific signing parameters which are fed into
+/// signing code to ensure correctly compliant signatures are computed.
+///
+/// They select the [`Ed25519Variant`] and its context string. The
+/// [`Default`] is plain Ed25519. For Ed25519ph, the message of the
+/// [`SigningTarget`] must be the 64-byte SHA-512 prehash of the message being
+/// signed, so that large messages do not need to be sent to every signer; the
+/// resulting signature verifies under standard Ed25519ph verifiers for the
+/// original message. [`SigningParameters::signing_target()`] computes it, and
+/// signing a target whose message is not 64 bytes long is rejected.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SigningParametersSerialization"))]
+#[cfg_attr(feature = "serde", serde(into = "SigningParametersSerialization"))]
+pub struct SigningParameters {
+    variant: Ed25519Variant,
+    context: Vec<u8>,
+}
+
+impl SigningParameters {
+    /// Create parameters for the given variant and context string.
+    ///
+    /// Returns [`Error::InvalidSigningParameters`] if the context is longer
+    /// than 255 bytes, if it is empty for Ed25519ctx, or if it is not empty
+    /// for plain Ed25519.
+    pub fn new(variant: Ed25519Variant, context: &[u8]) -> Result<Self, Error> {
+        let valid = match variant {
+            Ed25519Variant::Ed25519 => context.is_empty(),
+            Ed25519Variant::Ed25519ctx => !context.is_empty() && context.len() <= 255,
+            Ed25519Variant::Ed25519ph => context.len() <= 255,
+        };
+        if !valid {
+            return Err(Error::InvalidSigningParameters);
+        }
+        Ok(Self {
+            variant,
+            context: context.to_vec(),
+        })
+    }
+
+    /// Create parameters for Ed25519ctx with the given context string, which
+    /// must have between 1 and 255 bytes.
+    pub fn ed25519ctx(context: &[u8]) -> Result<Self, Error> {
+        Self::new(Ed25519Variant::Ed25519ctx, context)
+    }
+
+    /// Create parameters for Ed25519ph with the given context string, which
+    /// must have at most 255 bytes.
+    pub fn ed25519ph(context: &[u8]) -> Result<Self, Error> {
+        Self::new(Ed25519Variant::Ed25519ph, context)
+    }
+
+    /// Create the [`SigningTarget`] for `message` with these parameters. For
+    /// Ed25519ph, its message is the SHA-512 prehash of `message`.
+    pub fn signing_target(self, message: &[u8]) -> SigningTarget {
+        match self.variant {
+            Ed25519Variant::Ed25519ph => SigningTarget::new(Sha512::digest(message), self),
+            _ => SigningTarget::new(message, self),
+        }
+    }
+
+    /// The Ed25519 variant.
+    pub fn variant(&self) -> Ed25519Variant {
+        self.variant
+    }
+
+    /// The context string.
+    pub fn context(&self) -> &[u8] {
+        &self.context
+    }
+
+    /// `dom2(phflag, context)` as defined in RFC 8032, which is empty for
+    /// plain Ed25519.
+    pub(crate) fn dom2(&self) -> Vec<u8> {
+        let phflag = match self.variant {
+            Ed25519Variant::Ed25519 => return Vec::new(),
+            Ed25519Variant::Ed25519ctx => 0,
+            Ed25519Variant::Ed25519ph => 1,
+        };
+        [
+            DOM2_PREFIX,
+            &[phflag, self.context.len() as u8],
+            &self.context,
+        ]
+        .concat()
+    }
+}
+
+impl frost_core::SigningParameters for SigningParameters {
+    /// Encodes plain Ed25519 as no bytes, and the other variants as the
+    /// `dom2` phflag byte (`0x00` for Ed25519ctx, `0x01` for Ed25519ph),
+    /// followed by the context length byte and the context.
+    fn to_bytes(&self) -> Vec<u8> {
+        match self.dom2() {
+            dom2 if dom2.is_empty() => dom2,
+            dom2 => dom2[DOM2_PREFIX.len()..].to_vec(),
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let variant = match bytes.first() {
+            None => return Some(Self::default()),
+            Some(0) => Ed25519Variant::Ed25519ctx,
+            Some(1) => Ed25519Variant::Ed25519ph,
+            Some(_) => return None,
+        };
+        match &bytes[1..] {
+            [len, context @ ..] if *len as usize == context.len() => {
+                Self::new(variant, context).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Accepts only 64-byte prehashes for Ed25519ph.
+    fn accepts_message(&self, message: &[u8]) -> bool {
+        self.variant != Ed25519Variant::Ed25519ph || message.len() == PREHASH_LEN
+    }
+}
+
+/// The `serde` representation of [`SigningParameters`], which is validated
+/// when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SigningParametersSerialization {
+    variant: Ed25519Variant,
+    context: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SigningParametersSerialization> for SigningParameters {
+    type Error = Error;
+
+    fn try_from(value: SigningParametersSerialization) -> Result<Self, Self::Error> {
+        Self::new(value.variant, &value.context)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SigningParameters> for SigningParametersSerialization {
+    fn from(value: SigningParameters) -> Self {
+        Self {
+            variant: value.variant,
+            context: value.context,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::*;
-use frost_core::tests::proptests::{tweak_strategy, SignatureCase};
+use frost_core::tests::proptests::{check_pippenger_matches_straus, tweak_strategy, SignatureCase};
 use proptest::prelude::*;
 
 use rand_chacha::ChaChaRng;
@@ -29,5 +29,32 @@ proptest! {
         assert!(sig.check());
     }
 
+    #[test]
+    fn batch_verification_agrees_with_single(
+        tweaks in prop::collection::vec(tweak_strategy(), (0,5)),
+        rng_seed in prop::array::uniform32(any::<u8>()),
+    ) {
+        // Use a deterministic RNG so that test failures can be reproduced.
+        let mut rng = ChaChaRng::from_seed(rng_seed);
+
+        let msg = b"test message for proptests";
+        let mut sig = SignatureCase::<Ed25519Sha512>::new(&mut rng, msg.to_vec());
+
+        for t in &tweaks {
+            sig.apply_tweak(t);
+        }
+
+        assert!(sig.check_batch(rng));
+    }
+
+    #[test]
+    fn pippenger_matches_straus(
+        n in 0..32usize,
+        rng_seed in prop::array::uniform32(any::<u8>()),
+    ) {
+        // Use a deterministic RNG so that test failures can be reproduced.
+        let rng = ChaChaRng::from_seed(rng_seed);
 
+        assert!(check_pippenger_matches_straus::<Ed25519Sha512, _>(rng, n));
+    }
 }
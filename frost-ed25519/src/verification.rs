@@ -0,0 +1,151 @@
+//! Verification of Ed25519 signatures under the validation rules of other
+//! Ed25519 implementations.
+
+use curve25519_dalek::{
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+    traits::IsIdentity,
+};
+use frost_core::{batch, Challenge, Signature};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{hash_to_scalar, Ed25519Sha512, Error, SigningTarget, VerifyingKey};
+
+/// The validation rules an Ed25519 signature is verified under.
+///
+/// [`VerifyingKey::verify()`](frost_core::VerifyingKey::verify) only accepts
+/// canonically encoded points of prime order, for which all the rules below
+/// agree. Other implementations disagree on signatures with small-order,
+/// mixed-order or non-canonically encoded points, which can be verified from
+/// their encodings with
+/// [`VerifyingKey::verify_with_mode()`](frost_core::VerifyingKey::verify_with_mode),
+/// [`batch::Item::from_encodings()`] and [`batch::Verifier::with_mode()`]:
+///
+/// - [`VerificationMode::Zip215`] follows [ZIP-215], as used in consensus
+///   code: any encoding that decodes to a curve point is accepted for the
+///   verifying key and `R`, and the cofactored equation is used;
+/// - [`VerificationMode::Strict`] follows the strict rules of [RFC 8032] as
+///   implemented by e.g. `ed25519_dalek::VerifyingKey::verify_strict()`: the
+///   verifying key and `R` must be canonically encoded and not of small
+///   order, and the cofactorless equation is used.
+///
+/// In both modes, `s` must be canonically encoded.
+///
+/// [ZIP-215]: https://zips.z.cash/zip-0215
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.7
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum VerificationMode {
+    /// The rules of [ZIP-215](https://zips.z.cash/zip-0215). This is the
+    /// default, since its batch verification is as fast as the one of the
+    /// other ciphersuites.
+    #[default]
+    Zip215,
+    /// The strict, cofactorless rules of RFC 8032.
+    Strict,
+}
+
+/// Decode the encoded `signature` over `sig_target` and the encoded
+/// `verifying_key`.
+///
+/// Returns [`Error::MalformedVerifyingKey`] or [`Error::MalformedSignature`]
+/// if they are not valid in any [`VerificationMode`]: the points must decode
+/// to curve points and `s` must be canonically encoded.
+pub(crate) fn decode_item(
+    verifying_key: [u8; 32],
+    signature: [u8; 64],
+    sig_target: SigningTarget,
+) -> Result<batch::Item<Ed25519Sha512>, Error> {
+    let (R_bytes, s_bytes) = signature.split_at(32);
+
+    let A = CompressedEdwardsY(verifying_key)
+        .decompress()
+        .ok_or(Error::MalformedVerifyingKey)?;
+    let R = CompressedEdwardsY::from_slice(R_bytes)
+        .map_err(|_| Error::MalformedSignature)?
+        .decompress()
+        .ok_or(Error::MalformedSignature)?;
+    let s = Option::from(Scalar::from_canonical_bytes(
+        s_bytes.try_into().map_err(|_| Error::MalformedSignature)?,
+    ))
+    .ok_or(Error::MalformedSignature)?;
+
+    // The challenge is computed from the encodings as given, which may be
+    // non-canonical under ZIP-215.
+    let c = hash_to_scalar(&[
+        &sig_target.sig_params().dom2(),
+        R_bytes,
+        &verifying_key,
+        sig_target.message(),
+    ]);
+    let canonical = A.compress().as_bytes() == &verifying_key && R.compress().as_bytes() == R_bytes;
+
+    Ok(batch::Item::from_parts(
+        VerifyingKey::new(A),
+        Signature::new(R, s),
+        sig_target.sig_params().clone(),
+        Challenge::from_scalar(c),
+        canonical,
+    ))
+}
+
+/// Verify the signature of `item` under `mode`.
+pub(crate) fn verify_item(
+    mode: VerificationMode,
+    item: &batch::Item<Ed25519Sha512>,
+) -> Result<(), Error> {
+    check_encoding(mode, item)?;
+
+    let A = item.verifying_key().to_element();
+    let R = *item.signature().R();
+
+    // [s]B - [c]A
+    let sB_minus_cA = EdwardsPoint::vartime_double_scalar_mul_basepoint(
+        &item.challenge().clone().to_scalar(),
+        &-A,
+        item.signature().z(),
+    );
+    let valid = match mode {
+        VerificationMode::Zip215 => (sB_minus_cA - R).mul_by_cofactor().is_identity(),
+        VerificationMode::Strict => sB_minus_cA == R,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Verify the signatures of `items` under `mode`.
+///
+/// Under [`VerificationMode::Zip215`], the cofactored equation is batched with
+/// random coefficients. Under [`VerificationMode::Strict`], each signature is
+/// verified with [`verify_item()`], which brings no speedup: the cofactorless
+/// equation can not be batched without disagreeing with single verification,
+/// and checking that the points have no torsion component costs as much as
+/// verifying the signature.
+pub(crate) fn verify_batch<R: RngCore + CryptoRng>(
+    mode: VerificationMode,
+    items: &[batch::Item<Ed25519Sha512>],
+    rng: &mut R,
+) -> Result<(), Error> {
+    match mode {
+        VerificationMode::Zip215 => batch::verify_cofactored_by(Scalar::from(8u8), items, rng),
+        VerificationMode::Strict => items.iter().try_for_each(|item| verify_item(mode, item)),
+    }
+}
+
+/// Check the encoding rules of `mode` that are not already checked when
+/// decoding.
+fn check_encoding(mode: VerificationMode, item: &batch::Item<Ed25519Sha512>) -> Result<(), Error> {
+    match mode {
+        VerificationMode::Zip215 => Ok(()),
+        VerificationMode::Strict
+            if !item.is_canonical()
+                || item.verifying_key().to_element().is_small_order()
+                || item.signature().R().is_small_order() =>
+        {
+            Err(Error::InvalidSignature)
+        }
+        VerificationMode::Strict => Ok(()),
+    }
+}
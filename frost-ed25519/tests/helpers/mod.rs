@@ -24,3 +24,21 @@ pub fn verify_signature(
     // Check that signature validation has the expected result.
     assert!(pub_key.verify(msg, &sig).is_ok());
 }
+
+/// Check that `group_signature` is a valid Ed25519ph signature of `msg` with
+/// the given context.
+pub fn verify_prehashed_signature(
+    msg: &[u8],
+    context: &[u8],
+    group_signature: frost_core::Signature<Ed25519Sha512>,
+    group_pubkey: frost_core::VerifyingKey<Ed25519Sha512>,
+) {
+    use ed25519_dalek::Digest;
+
+    let sig = ed25519_dalek::Signature::from(group_signature.serialize());
+    let pub_key = ed25519_dalek::VerifyingKey::from_bytes(&group_pubkey.serialize()).unwrap();
+    let prehashed = ed25519_dalek::Sha512::new().chain_update(msg);
+    assert!(pub_key
+        .verify_prehashed(prehashed, Some(context), &sig)
+        .is_ok());
+}
@@ -1,6 +1,6 @@
 use crate::Ed25519Sha512;
 use frost_ed25519::*;
-use rand::thread_rng;
+use rand::{thread_rng, RngCore};
 
 mod helpers;
 
@@ -40,3 +40,45 @@ fn check_interoperability_in_sign_with_dealer() {
         helpers::verify_signature(target.message(), group_signature, group_pubkey);
     }
 }
+
+#[test]
+fn check_interoperability_in_sign_deterministic() {
+    use ed25519_dalek::Signer;
+    use sha2::{Digest, Sha512};
+
+    let mut rng = thread_rng();
+    let message = b"message";
+
+    for _ in 0..32 {
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        let expanded_secret_key = keys::rfc8032::ExpandedSecretKey::from_seed(&seed);
+        let dalek_signing_key = ed25519_dalek::SigningKey::from_bytes(&seed);
+
+        // Plain Ed25519 signatures of a key imported from a seed are the same
+        // as those of `ed25519_dalek` with the same seed.
+        let signature = expanded_secret_key.sign(message).unwrap();
+        assert_eq!(
+            signature.serialize(),
+            dalek_signing_key.sign(message).to_bytes()
+        );
+
+        // So are Ed25519ph signatures.
+        let signature = expanded_secret_key
+            .sign(SigningTarget::new(
+                Sha512::digest(message),
+                SigningParameters::ed25519ph(b"context").unwrap(),
+            ))
+            .unwrap();
+        let dalek_signature = dalek_signing_key
+            .sign_prehashed(Sha512::new().chain_update(message), Some(b"context"))
+            .unwrap();
+        assert_eq!(signature.serialize(), dalek_signature.to_bytes());
+
+        // Keys without a seed sign with a nonce prefix derived from the
+        // secret scalar instead, which `ed25519_dalek` still verifies.
+        let signing_key = expanded_secret_key.signing_key();
+        let signature = signing_key.sign_deterministic(message).unwrap();
+        helpers::verify_signature(message, signature, signing_key.into());
+    }
+}
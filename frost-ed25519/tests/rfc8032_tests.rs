@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use ed25519_dalek::Digest;
+use frost_ed25519::{keys::rfc8032, *};
+use rand::{thread_rng, RngCore};
+use sha2::Sha512;
+
+mod helpers;
+
+fn random_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    thread_rng().fill_bytes(&mut seed);
+    seed
+}
+
+/// The public key of `seed` computed by `ed25519_dalek`.
+fn dalek_public_key(seed: &[u8; 32]) -> [u8; 32] {
+    ed25519_dalek::SigningKey::from_bytes(seed)
+        .verifying_key()
+        .to_bytes()
+}
+
+/// Sign `message` with 3 of the 5 shares and check that `ed25519_dalek`
+/// verifies the signature with `public_key`.
+fn check_split_signing(
+    shares: BTreeMap<Identifier, keys::SecretShare>,
+    pubkey_package: keys::PublicKeyPackage,
+    public_key: [u8; 32],
+) {
+    let key_packages = shares
+        .into_iter()
+        .map(|(identifier, share)| (identifier, share.try_into().unwrap()))
+        .collect();
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign(
+            3,
+            key_packages,
+            thread_rng(),
+            pubkey_package,
+            b"message".into(),
+        )
+        .unwrap();
+
+    assert_eq!(group_pubkey.serialize(), public_key);
+    helpers::verify_signature(target.message(), group_signature, group_pubkey);
+}
+
+#[test]
+fn check_signing_key_from_seed() {
+    for _ in 0..32 {
+        let seed = random_seed();
+        let signing_key = rfc8032::signing_key_from_seed(&seed);
+
+        assert_eq!(
+            VerifyingKey::from(signing_key).serialize(),
+            dalek_public_key(&seed)
+        );
+    }
+}
+
+/// Test vector from RFC 8032, section 7.1 (TEST 1).
+#[test]
+fn check_signing_key_from_seed_rfc8032_vector() {
+    let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    assert_eq!(
+        hex::encode(VerifyingKey::from(rfc8032::signing_key_from_seed(&seed)).serialize()),
+        "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+    );
+}
+
+/// Test vector from RFC 8032, section 7.1 (TEST 1).
+#[test]
+fn check_expanded_secret_key_sign_rfc8032_vector() {
+    let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let expanded_secret_key = rfc8032::ExpandedSecretKey::from_seed(&seed);
+
+    assert_eq!(
+        hex::encode(expanded_secret_key.sign(b"").unwrap().serialize()),
+        "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+    );
+}
+
+#[test]
+fn check_signing_key_from_expanded_secret_key() {
+    let seed = random_seed();
+    let expanded_secret_key: [u8; 64] = Sha512::digest(seed).into();
+
+    assert_eq!(
+        rfc8032::signing_key_from_expanded_secret_key(&expanded_secret_key),
+        rfc8032::signing_key_from_seed(&seed)
+    );
+
+    // `ed25519_dalek` derives the same scalar from the expanded secret key.
+    let expanded = ed25519_dalek::hazmat::ExpandedSecretKey::from_bytes(&expanded_secret_key);
+    assert_eq!(
+        rfc8032::signing_key_from_expanded_secret_key(&expanded_secret_key).serialize(),
+        expanded.scalar.to_bytes()
+    );
+}
+
+#[test]
+fn check_interoperability_in_split_seed() {
+    let mut rng = thread_rng();
+    let seed = random_seed();
+
+    let (shares, pubkey_package) =
+        rfc8032::split_seed(&seed, 5, 3, keys::IdentifierList::Default, &mut rng).unwrap();
+
+    assert_eq!(
+        pubkey_package.verifying_key().serialize(),
+        dalek_public_key(&seed)
+    );
+    check_split_signing(shares, pubkey_package, dalek_public_key(&seed));
+}
+
+#[test]
+fn check_interoperability_in_split_expanded_secret_key() {
+    let mut rng = thread_rng();
+    let seed = random_seed();
+    let expanded_secret_key: [u8; 64] = Sha512::digest(seed).into();
+
+    let (shares, pubkey_package) = rfc8032::split_expanded_secret_key(
+        &expanded_secret_key,
+        5,
+        3,
+        keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    assert_eq!(
+        pubkey_package.verifying_key().serialize(),
+        dalek_public_key(&seed)
+    );
+    check_split_signing(shares, pubkey_package, dalek_public_key(&seed));
+}
@@ -0,0 +1,365 @@
+use ed25519_dalek::Digest;
+use frost_core::SigningParameters as _;
+use frost_ed25519::*;
+use rand::thread_rng;
+use sha2::Sha512;
+
+mod helpers;
+
+fn ed25519ctx(context: &[u8]) -> SigningParameters {
+    SigningParameters::ed25519ctx(context).unwrap()
+}
+
+fn ed25519ph(context: &[u8]) -> SigningParameters {
+    SigningParameters::ed25519ph(context).unwrap()
+}
+
+#[test]
+fn check_signing_parameters_validation() {
+    assert_eq!(
+        SigningParameters::default().variant(),
+        Ed25519Variant::Ed25519
+    );
+
+    assert_eq!(
+        SigningParameters::ed25519ctx(b""),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        SigningParameters::ed25519ctx(&[0xab; 256]),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        SigningParameters::ed25519ph(&[0xab; 256]),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        SigningParameters::new(Ed25519Variant::Ed25519, b"foo"),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert!(SigningParameters::ed25519ctx(&[0xab; 255]).is_ok());
+    assert!(SigningParameters::ed25519ph(b"").is_ok());
+}
+
+#[test]
+fn check_signing_parameters_bytes() {
+    for params in [
+        SigningParameters::default(),
+        ed25519ctx(b"foo"),
+        ed25519ph(b""),
+        ed25519ph(&[0xab; 255]),
+    ] {
+        assert_eq!(
+            SigningParameters::from_bytes(&params.to_bytes()),
+            Some(params)
+        );
+    }
+
+    // Plain Ed25519 is encoded as no bytes, as when the parameters were `()`.
+    assert!(SigningParameters::default().to_bytes().is_empty());
+    assert_eq!(ed25519ctx(b"foo").to_bytes(), b"\x00\x03foo");
+    assert_eq!(ed25519ph(b"").to_bytes(), b"\x01\x00");
+
+    for bytes in [
+        &b"\x02\x00"[..],
+        b"\x00\x00",
+        b"\x00\x03fo",
+        b"\x00\x03fooo",
+        b"\x01",
+    ] {
+        assert_eq!(SigningParameters::from_bytes(bytes), None);
+    }
+}
+
+/// Test vector from RFC 8032, section 7.2 (Ed25519ctx, context "foo").
+#[test]
+fn check_ed25519ctx_rfc8032_vector() {
+    let verifying_key = VerifyingKey::deserialize(
+        hex::decode("dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    )
+    .unwrap();
+    let message = hex::decode("f726936d19c800494e3fdaff20b276a8").unwrap();
+    let signature = Signature::deserialize(
+        hex::decode(
+            "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a\
+             8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap(),
+    )
+    .unwrap();
+
+    verifying_key
+        .verify(SigningTarget::new(&message, ed25519ctx(b"foo")), &signature)
+        .expect("signature should be valid for its context");
+    verifying_key
+        .verify(SigningTarget::new(&message, ed25519ctx(b"bar")), &signature)
+        .expect_err("signature should not be valid for another context");
+    verifying_key
+        .verify(&message, &signature)
+        .expect_err("signature should not be valid as plain Ed25519");
+}
+
+/// Test vector from RFC 8032, section 7.3 (Ed25519ph, no context).
+#[test]
+fn check_ed25519ph_rfc8032_vector() {
+    let verifying_key = VerifyingKey::deserialize(
+        hex::decode("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    )
+    .unwrap();
+    let signature = Signature::deserialize(
+        hex::decode(
+            "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae41\
+             31f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap(),
+    )
+    .unwrap();
+
+    let prehash = Sha512::digest(b"abc");
+    verifying_key
+        .verify(SigningTarget::new(prehash, ed25519ph(b"")), &signature)
+        .expect("signature should be valid for the prehashed message");
+}
+
+#[test]
+fn check_ed25519ctx_sign_with_dkg() {
+    let rng = thread_rng();
+
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Ed25519Sha512, _>(
+            rng,
+            SigningTarget::new(b"message", ed25519ctx(b"context")),
+        );
+
+    group_pubkey
+        .verify(target.message(), &group_signature)
+        .expect_err("signature should not be valid as plain Ed25519");
+    group_pubkey
+        .verify(
+            SigningTarget::new(target.message(), ed25519ph(b"context")),
+            &group_signature,
+        )
+        .expect_err("signature should not be valid as Ed25519ph");
+}
+
+#[test]
+fn check_ed25519ctx_sign_with_dealer_and_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dealer_and_identifiers::<
+        Ed25519Sha512,
+        _,
+    >(rng, SigningTarget::new(b"message", ed25519ctx(b"context")));
+}
+
+#[test]
+fn check_interoperability_in_ed25519ph_sign_with_dealer() {
+    let rng = thread_rng();
+    let message = b"a large firmware image";
+
+    for context in [&b""[..], b"firmware"] {
+        // The signers only see the prehash of the message.
+        let (_, group_signature, group_pubkey) =
+            frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Ed25519Sha512, _>(
+                rng.clone(),
+                SigningTarget::new(Sha512::digest(message), ed25519ph(context)),
+            );
+
+        // Check that the threshold signature can be verified by the
+        // `ed25519_dalek` crate as an Ed25519ph signature of the message.
+        helpers::verify_prehashed_signature(message, context, group_signature, group_pubkey);
+    }
+}
+
+#[test]
+fn check_interoperability_in_ed25519ph_sign_with_dkg() {
+    let rng = thread_rng();
+    let message = b"a large firmware image";
+
+    let (_, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Ed25519Sha512, _>(
+            rng,
+            SigningTarget::new(Sha512::digest(message), ed25519ph(b"firmware")),
+        );
+
+    helpers::verify_prehashed_signature(message, b"firmware", group_signature, group_pubkey);
+}
+
+#[test]
+fn check_ed25519ph_single_signer() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let message = b"message";
+
+    let signature = signing_key.sign(&mut rng, ed25519ph(b"context").signing_target(message));
+
+    helpers::verify_prehashed_signature(
+        message,
+        b"context",
+        signature,
+        VerifyingKey::from(signing_key),
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_variant_signing_package_serialization() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let signing_share = keys::SigningShare::deserialize([1; 32]).unwrap();
+    let (_, commitments) = round1::commit(&signing_share, &mut rng);
+    let identifier = Identifier::try_from(1).unwrap();
+
+    for params in [
+        SigningParameters::default(),
+        ed25519ctx(b"context"),
+        ed25519ph(b""),
+    ] {
+        let signing_package = SigningPackage::new(
+            BTreeMap::from([(identifier, commitments)]),
+            params.signing_target(b"message"),
+        );
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            SigningPackage::deserialize(&bytes).unwrap(),
+            signing_package
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_signing_parameters_serde() {
+    let params = ed25519ctx(b"foo");
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SigningParameters>(&json).unwrap(),
+        params
+    );
+
+    let invalid = r#"{"variant":"Ed25519ctx","context":[]}"#;
+    assert!(serde_json::from_str::<SigningParameters>(invalid).is_err());
+}
+
+#[test]
+fn check_ed25519ph_signing_target() {
+    let message = b"a large firmware image";
+
+    let target = ed25519ph(b"firmware").signing_target(message);
+    assert_eq!(target.message(), &Sha512::digest(message)[..]);
+    let target = ed25519ctx(b"firmware").signing_target(message);
+    assert_eq!(target.message(), message);
+
+    assert!(!ed25519ph(b"").accepts_message(message));
+    assert!(ed25519ph(b"").accepts_message(&Sha512::digest(message)));
+    assert!(ed25519ctx(b"firmware").accepts_message(message));
+}
+
+#[test]
+fn check_ed25519ph_sign_rejects_unhashed_message() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let target = SigningTarget::new(b"message", ed25519ph(b""));
+
+    assert_eq!(
+        signing_key.try_sign(&mut rng, target.clone()),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        signing_key.sign_deterministic(target),
+        Err(Error::InvalidSigningParameters)
+    );
+}
+
+#[test]
+fn check_ed25519ph_signing_package_rejects_unhashed_message() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let (shares, pubkeys) =
+        keys::generate_with_dealer(2, 2, keys::IdentifierList::Default, &mut rng).unwrap();
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    let mut key_packages = BTreeMap::new();
+    for (identifier, share) in shares {
+        let key_package = keys::KeyPackage::try_from(share).unwrap();
+        let (signing_nonces, signing_commitments) =
+            round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(identifier, signing_nonces);
+        commitments.insert(identifier, signing_commitments);
+        key_packages.insert(identifier, key_package);
+    }
+
+    // The package can be built, but neither signed nor aggregated.
+    let signing_package = SigningPackage::new(
+        commitments.clone(),
+        SigningTarget::new(b"message", ed25519ph(b"")),
+    );
+    for (identifier, key_package) in &key_packages {
+        assert_eq!(
+            round2::sign(&signing_package, &nonces[identifier], key_package),
+            Err(Error::InvalidSigningParameters)
+        );
+    }
+
+    let prehashed_package = SigningPackage::new(
+        commitments,
+        SigningTarget::new(Sha512::digest(b"message"), ed25519ph(b"")),
+    );
+    let signature_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let share = round2::sign(&prehashed_package, &nonces[identifier], key_package).unwrap();
+            (*identifier, share)
+        })
+        .collect();
+    aggregate(&prehashed_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(
+        aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::InvalidSigningParameters)
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_ed25519ph_signing_package_deserialization_rejects_unhashed_message() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let signing_share = keys::SigningShare::deserialize([1; 32]).unwrap();
+    let (_, commitments) = round1::commit(&signing_share, &mut rng);
+    let identifier = Identifier::try_from(1).unwrap();
+
+    // Replace the 64-byte prehash and its length prefix with a message that
+    // is not a prehash.
+    let signing_package = SigningPackage::new(
+        BTreeMap::from([(identifier, commitments)]),
+        ed25519ph(b"").signing_target(b"message"),
+    );
+    let bytes = signing_package.serialize().unwrap();
+    let prehash = signing_package.message();
+    let position = bytes
+        .windows(prehash.len())
+        .position(|window| window == prehash)
+        .unwrap();
+    let mut modified = bytes[..position - 4].to_vec();
+    modified.extend_from_slice(&7u32.to_be_bytes());
+    modified.extend_from_slice(b"message");
+    modified.extend_from_slice(&bytes[position + prehash.len()..]);
+
+    assert_eq!(
+        SigningPackage::deserialize(&modified),
+        Err(Error::InvalidSigningParameters)
+    );
+}
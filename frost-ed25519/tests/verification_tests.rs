@@ -0,0 +1,262 @@
+#![allow(non_snake_case)]
+
+use curve25519_dalek::{
+    constants::{ED25519_BASEPOINT_POINT, EIGHT_TORSION},
+    edwards::EdwardsPoint,
+    scalar::Scalar,
+};
+use frost_core::batch;
+use frost_ed25519::*;
+use rand::{thread_rng, RngCore};
+use sha2::{Digest, Sha512};
+
+const MESSAGE: &[u8] = b"message";
+
+/// The canonical encoding of the identity, which has small order.
+const IDENTITY: [u8; 32] = {
+    let mut bytes = [0; 32];
+    bytes[0] = 1;
+    bytes
+};
+
+/// A non-canonical encoding of the identity, with y = p + 1.
+const NON_CANONICAL_IDENTITY: [u8; 32] = {
+    let mut bytes = [0xff; 32];
+    bytes[0] = 0xee;
+    bytes[31] = 0x7f;
+    bytes
+};
+
+fn random_scalar() -> Scalar {
+    let mut bytes = [0; 64];
+    thread_rng().fill_bytes(&mut bytes);
+    Scalar::from_bytes_mod_order_wide(&bytes)
+}
+
+fn challenge(R: &[u8], A: &[u8]) -> Scalar {
+    Scalar::from_hash(
+        Sha512::new()
+            .chain_update(R)
+            .chain_update(A)
+            .chain_update(MESSAGE),
+    )
+}
+
+/// A signature of `MESSAGE` whose `R` has a torsion component `T`, which
+/// satisfies the cofactored equation but not the cofactorless one.
+fn mixed_order_signature(T: EdwardsPoint) -> ([u8; 32], [u8; 64]) {
+    let a = random_scalar();
+    let A = (ED25519_BASEPOINT_POINT * a).compress().to_bytes();
+    let k = random_scalar();
+    let R = (ED25519_BASEPOINT_POINT * k + T).compress().to_bytes();
+    let s = k + challenge(&R, &A) * a;
+    (A, [R, s.to_bytes()].concat().try_into().unwrap())
+}
+
+/// A signature of `MESSAGE` with `s = 0` whose verifying key `A` and `R` have
+/// small order, encoded as given.
+fn small_order_signature(A: [u8; 32], R: [u8; 32]) -> ([u8; 32], [u8; 64]) {
+    (A, [R, [0; 32]].concat().try_into().unwrap())
+}
+
+/// A valid signature of `MESSAGE` made with FROST.
+fn frost_signature() -> ([u8; 32], [u8; 64]) {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let signature = signing_key.sign(&mut rng, MESSAGE);
+    (
+        VerifyingKey::from(signing_key).serialize(),
+        signature.serialize(),
+    )
+}
+
+fn verify(mode: VerificationMode, (A, signature): ([u8; 32], [u8; 64])) -> Result<(), Error> {
+    batch::Item::<Ed25519Sha512>::from_encodings(A, signature, MESSAGE)?
+        .verify_single_with_mode(mode)
+}
+
+/// Whether `ed25519_dalek::VerifyingKey::verify_strict()` accepts the
+/// signature.
+fn dalek_verify_strict((A, signature): ([u8; 32], [u8; 64])) -> bool {
+    ed25519_dalek::VerifyingKey::from_bytes(&A)
+        .and_then(|vk| vk.verify_strict(MESSAGE, &ed25519_dalek::Signature::from(signature)))
+        .is_ok()
+}
+
+#[test]
+fn check_prime_order_signature() {
+    let (A, signature) = frost_signature();
+
+    for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+        verify(mode, (A, signature)).expect("signature should be valid in every mode");
+        VerifyingKey::deserialize(A)
+            .unwrap()
+            .verify_with_mode(mode, MESSAGE, signature)
+            .expect("signature should be valid in every mode");
+    }
+    assert!(dalek_verify_strict((A, signature)));
+
+    let mut tampered = signature;
+    tampered[40] ^= 1;
+    for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+        assert!(verify(mode, (A, tampered)).is_err());
+    }
+}
+
+#[test]
+fn check_signing_parameters() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(signing_key);
+    let sig_target =
+        SigningTarget::new(MESSAGE, SigningParameters::ed25519ctx(b"context").unwrap());
+    let signature = signing_key.sign(&mut rng, sig_target.clone()).serialize();
+
+    for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+        verifying_key
+            .verify_with_mode(mode, sig_target.clone(), signature)
+            .expect("signature should be valid for its context");
+        verifying_key
+            .verify_with_mode(mode, MESSAGE, signature)
+            .expect_err("signature should not be valid as plain Ed25519");
+    }
+}
+
+#[test]
+fn check_small_order_points() {
+    let order_8 = EIGHT_TORSION[1].compress().to_bytes();
+
+    for vector in [
+        small_order_signature(IDENTITY, IDENTITY),
+        small_order_signature(order_8, IDENTITY),
+        small_order_signature(IDENTITY, order_8),
+    ] {
+        // Such keys can not be deserialized at all.
+        assert!(VerifyingKey::deserialize(vector.0).is_err());
+
+        verify(VerificationMode::Zip215, vector).expect("ZIP-215 accepts small-order points");
+        assert_eq!(
+            verify(VerificationMode::Strict, vector),
+            Err(Error::InvalidSignature)
+        );
+        assert!(!dalek_verify_strict(vector));
+    }
+}
+
+#[test]
+fn check_non_canonical_points() {
+    for vector in [
+        small_order_signature(NON_CANONICAL_IDENTITY, IDENTITY),
+        small_order_signature(IDENTITY, NON_CANONICAL_IDENTITY),
+    ] {
+        verify(VerificationMode::Zip215, vector).expect("ZIP-215 accepts non-canonical encodings");
+        assert_eq!(
+            verify(VerificationMode::Strict, vector),
+            Err(Error::InvalidSignature)
+        );
+        assert!(!dalek_verify_strict(vector));
+    }
+}
+
+#[test]
+fn check_mixed_order_points() {
+    for T in &EIGHT_TORSION[1..] {
+        let vector = mixed_order_signature(*T);
+
+        // Such signatures can not be deserialized at all.
+        assert!(Signature::deserialize(vector.1).is_err());
+
+        verify(VerificationMode::Zip215, vector).expect("ZIP-215 uses the cofactored equation");
+        assert_eq!(
+            verify(VerificationMode::Strict, vector),
+            Err(Error::InvalidSignature)
+        );
+        assert!(!dalek_verify_strict(vector));
+    }
+}
+
+#[test]
+fn check_malformed_encodings() {
+    let (A, signature) = frost_signature();
+
+    // s = L is not canonically encoded.
+    let mut non_canonical_s = signature;
+    non_canonical_s[32..].copy_from_slice(
+        &hex::decode("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010").unwrap(),
+    );
+    // y = 2 does not decode to a curve point.
+    let mut undecodable = [0; 32];
+    undecodable[0] = 2;
+
+    for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+        assert_eq!(
+            verify(mode, (A, non_canonical_s)).unwrap_err(),
+            Error::MalformedSignature
+        );
+        assert_eq!(
+            verify(mode, (undecodable, signature)).unwrap_err(),
+            Error::MalformedVerifyingKey
+        );
+    }
+}
+
+#[test]
+fn check_batch_verification() {
+    let valid = [frost_signature(), frost_signature()];
+    let torsion = [
+        mixed_order_signature(EIGHT_TORSION[3]),
+        small_order_signature(IDENTITY, IDENTITY),
+        small_order_signature(NON_CANONICAL_IDENTITY, IDENTITY),
+    ];
+    let mut invalid = frost_signature();
+    invalid.1[40] ^= 1;
+
+    let verify_batch = |mode, vectors: &[([u8; 32], [u8; 64])]| {
+        let mut verifier = batch::Verifier::<Ed25519Sha512>::with_mode(mode);
+        for (A, signature) in vectors {
+            verifier.queue(batch::Item::from_encodings(*A, *signature, MESSAGE).unwrap());
+        }
+        verifier.verify(thread_rng())
+    };
+
+    for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+        assert!(verify_batch(mode, &valid).is_ok());
+        assert!(verify_batch(mode, &[valid[0], invalid, valid[1]]).is_err());
+        assert!(verify_batch(mode, &[]).is_err());
+    }
+
+    // The batch agrees with single verification.
+    for vector in torsion {
+        for mode in [VerificationMode::Zip215, VerificationMode::Strict] {
+            assert_eq!(
+                verify_batch(mode, &[valid[0], vector, valid[1]]).is_ok(),
+                verify(mode, vector).is_ok()
+            );
+        }
+    }
+    assert!(verify_batch(
+        VerificationMode::Zip215,
+        &[valid[0], valid[1], torsion[0], torsion[1]]
+    )
+    .is_ok());
+
+    // Strict verification locates the signatures that ZIP-215 accepts.
+    let mut verifier = batch::Verifier::<Ed25519Sha512>::with_mode(VerificationMode::Strict);
+    for (A, signature) in [valid[0], torsion[0], valid[1], torsion[1]] {
+        verifier.queue(batch::Item::from_encodings(A, signature, MESSAGE).unwrap());
+    }
+    assert_eq!(verifier.verify_and_locate(thread_rng()), Err(vec![1, 3]));
+}
+
+#[test]
+fn check_default_mode() {
+    let (A, signature) = small_order_signature(IDENTITY, IDENTITY);
+    assert_eq!(VerificationMode::default(), VerificationMode::Zip215);
+
+    // `Verifier::new()` and `Item::verify_single()` use the default mode.
+    let item = batch::Item::<Ed25519Sha512>::from_encodings(A, signature, MESSAGE).unwrap();
+    let mut verifier = batch::Verifier::new();
+    verifier.queue(item.clone());
+    assert!(verifier.verify(thread_rng()).is_ok());
+    assert!(item.verify_single().is_ok());
+}
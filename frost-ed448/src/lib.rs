@@ -5,7 +5,10 @@
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::sync::OnceLock;
 
 use ed448_goldilocks::{
     curve::{edwards::CompressedEdwardsY, ExtendedPoint},
@@ -17,15 +20,20 @@ use sha3::{
     digest::{ExtendableOutput, Update, XofReader},
     Shake256,
 };
+use zeroize::Zeroizing;
 
-use frost_core as frost;
+use frost_core::{self as frost, GeneratorTable};
 
+mod signing_parameters;
 #[cfg(test)]
 mod tests;
 
 // Re-exports in our public API
-pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError};
+pub use frost_core::{
+    serde, Challenge, Ciphersuite, Element, Field, FieldError, Group, GroupError,
+};
 pub use rand_core;
+pub use signing_parameters::{Ed448Variant, SigningParameters};
 
 /// An error.
 pub type Error = frost_core::Error<Ed448Shake256>;
@@ -98,6 +106,11 @@ impl Group for Ed448Group {
         Self::Element::generator()
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        static TABLE: OnceLock<GeneratorTable<Ed448Group>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::new).mul(scalar)
+    }
+
     fn serialize(element: &Self::Element) -> Self::Serialization {
         element.compress().0
     }
@@ -150,10 +163,6 @@ const CONTEXT_STRING: &str = "FROST-ED448-SHAKE256-v1";
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct Ed448Shake256;
 
-/// The ciphersuite-specific signing parameters which are fed into
-/// signing code to ensure correctly compliant signatures are computed.
-pub type SigningParameters = ();
-
 /// The message target which the group's signature should commit to. Includes
 /// a message byte vector, and a set of ciphersuite-specific parameters.
 pub type SigningTarget = frost_core::SigningTarget<Ed448Shake256>;
@@ -167,7 +176,12 @@ impl Ciphersuite for Ed448Shake256 {
 
     type SignatureSerialization = [u8; 114];
 
-    type SigningParameters = ();
+    type SigningParameters = SigningParameters;
+
+    // Unlike for Ed25519, RFC 8032 mandates both the cofactored equation and
+    // canonical encodings for Ed448, and its implementations follow it, so
+    // there are no competing validation rules to choose from.
+    type VerificationMode = ();
 
     /// H1 for FROST(Ed448, SHAKE256)
     ///
@@ -213,6 +227,52 @@ impl Ciphersuite for Ed448Shake256 {
     fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
         Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
     }
+
+    /// Generates the challenge as is required for Schnorr signatures,
+    /// prefixed with the `dom4` of the signing parameters instead of the
+    /// empty-context one of H2.
+    fn challenge(
+        R: &Element<E>,
+        verifying_key: &VerifyingKey,
+        sig_target: &SigningTarget,
+    ) -> Challenge<E> {
+        Challenge::from_scalar(hash_to_scalar(&[
+            &sig_target.sig_params().dom4(),
+            &R.compress().0,
+            &verifying_key.serialize(),
+            sig_target.message(),
+        ]))
+    }
+
+    /// Derives the nonce as in RFC 8032, `SHAKE256(dom4 || prefix || M, 114)`,
+    /// where `M` is the message of the signing target (the prehash for
+    /// Ed448ph).
+    ///
+    /// Since the signing key is a scalar rather than an RFC 8032 seed, the
+    /// 57-byte `prefix` is the first half of
+    /// `SHAKE256(CONTEXT_STRING || "prefix" || secret || aux, 114)`. Keys imported
+    /// from a seed sign with the prefix of the seed with
+    /// [`keys::rfc8032::ExpandedSecretKey::sign()`] instead, which gives the
+    /// same signatures as other RFC 8032 implementations.
+    fn deterministic_nonce(
+        secret: &Scalar,
+        _verifying_key: &VerifyingKey,
+        aux: &[u8],
+        sig_target: &SigningTarget,
+    ) -> Scalar {
+        let secret = Zeroizing::new(Ed448ScalarField::serialize(secret));
+        let prefix = Zeroizing::new(hash_to_array(&[
+            CONTEXT_STRING.as_bytes(),
+            b"prefix",
+            &secret[..],
+            aux,
+        ]));
+        hash_to_scalar(&[
+            &sig_target.sig_params().dom4(),
+            &prefix[..57],
+            sig_target.message(),
+        ])
+    }
 }
 
 impl RandomizedCiphersuite for Ed448Shake256 {
@@ -233,7 +293,7 @@ pub type Identifier = frost::Identifier<E>;
 /// FROST(Ed448, SHAKE256) keys, key generation, key shares.
 pub mod keys {
     use super::*;
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     /// The identifier list to use when generating key shares.
     pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, E>;
@@ -323,8 +383,13 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<E>;
 
+    /// A description of a FROST(Ed448, SHAKE256) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<E>;
+
     pub mod dkg;
     pub mod repairable;
+    pub mod rfc8032;
 }
 
 /// FROST(Ed448, SHAKE256) Round 1 functionality and types.
@@ -390,6 +455,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(Ed448, SHAKE256) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(Ed448, SHAKE256) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<E>;
+
+    /// Encrypt FROST(Ed448, SHAKE256) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(Ed448, SHAKE256) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(Ed448, SHAKE256).
 pub type Signature = frost_core::Signature<E>;
 
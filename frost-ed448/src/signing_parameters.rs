@@ -0,0 +1,182 @@
+//! The signing parameters of the Ed448 variants from [RFC 8032].
+//!
+//! [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+
+use alloc::vec::Vec;
+
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Shake256,
+};
+
+use crate::{Error, SigningTarget};
+
+/// The prefix of `dom4` in [RFC 8032], which is hashed before the rest of the
+/// challenge in every Ed448 variant.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+const DOM4_PREFIX: &[u8] = b"SigEd448";
+
+/// The length of the SHAKE256 prehash signed by Ed448ph.
+const PREHASH_LEN: usize = 64;
+
+/// The variant of Ed448 from [RFC 8032] that signatures are produced for.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ed448Variant {
+    /// Plain Ed448, which prefixes the challenge with `dom4(0, context)`.
+    #[default]
+    Ed448,
+    /// Ed448ph, which prefixes the challenge with `dom4(1, context)` and signs
+    /// the SHAKE256 prehash of the message.
+    Ed448ph,
+}
+
+/// The ciphersuite-specific signing parameters which are fed into
+/// signing code to ensure correctly compliant signatures are computed.
+///
+/// They select the [`Ed448Variant`] and its context string. The [`Default`]
+/// is plain Ed448 with an empty context. For Ed448ph, the message of the
+/// [`SigningTarget`] must be the 64-byte SHAKE256 prehash of the message being
+/// signed, which [`SigningParameters::signing_target()`] computes; signing a
+/// target whose message is not 64 bytes long is rejected.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SigningParametersSerialization"))]
+#[cfg_attr(feature = "serde", serde(into = "SigningParametersSerialization"))]
+pub struct SigningParameters {
+    variant: Ed448Variant,
+    context: Vec<u8>,
+}
+
+impl SigningParameters {
+    /// Create parameters for the given variant and context string.
+    ///
+    /// Returns [`Error::InvalidSigningParameters`] if the context is longer
+    /// than 255 bytes.
+    pub fn new(variant: Ed448Variant, context: &[u8]) -> Result<Self, Error> {
+        if context.len() > 255 {
+            return Err(Error::InvalidSigningParameters);
+        }
+        Ok(Self {
+            variant,
+            context: context.to_vec(),
+        })
+    }
+
+    /// Create parameters for plain Ed448 with the given context string, which
+    /// must have at most 255 bytes.
+    pub fn ed448(context: &[u8]) -> Result<Self, Error> {
+        Self::new(Ed448Variant::Ed448, context)
+    }
+
+    /// Create parameters for Ed448ph with the given context string, which
+    /// must have at most 255 bytes.
+    pub fn ed448ph(context: &[u8]) -> Result<Self, Error> {
+        Self::new(Ed448Variant::Ed448ph, context)
+    }
+
+    /// Create the [`SigningTarget`] for `message` with these parameters. For
+    /// Ed448ph, its message is the 64-byte SHAKE256 prehash of `message`.
+    pub fn signing_target(self, message: &[u8]) -> SigningTarget {
+        match self.variant {
+            Ed448Variant::Ed448 => SigningTarget::new(message, self),
+            Ed448Variant::Ed448ph => {
+                let mut prehash = [0u8; PREHASH_LEN];
+                Shake256::default()
+                    .chain(message)
+                    .finalize_xof_into(&mut prehash);
+                SigningTarget::new(prehash, self)
+            }
+        }
+    }
+
+    /// The Ed448 variant.
+    pub fn variant(&self) -> Ed448Variant {
+        self.variant
+    }
+
+    /// The context string.
+    pub fn context(&self) -> &[u8] {
+        &self.context
+    }
+
+    /// `dom4(phflag, context)` as defined in RFC 8032.
+    pub(crate) fn dom4(&self) -> Vec<u8> {
+        let phflag = match self.variant {
+            Ed448Variant::Ed448 => 0,
+            Ed448Variant::Ed448ph => 1,
+        };
+        [
+            DOM4_PREFIX,
+            &[phflag, self.context.len() as u8],
+            &self.context,
+        ]
+        .concat()
+    }
+}
+
+impl frost_core::SigningParameters for SigningParameters {
+    /// Encodes the default parameters (plain Ed448 with an empty context) as
+    /// no bytes, and the others as the `dom4` phflag byte (`0x00` for Ed448,
+    /// `0x01` for Ed448ph), followed by the context length byte and the
+    /// context.
+    fn to_bytes(&self) -> Vec<u8> {
+        if *self == Self::default() {
+            return Vec::new();
+        }
+        self.dom4()[DOM4_PREFIX.len()..].to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let variant = match bytes.first() {
+            None => return Some(Self::default()),
+            Some(0) => Ed448Variant::Ed448,
+            Some(1) => Ed448Variant::Ed448ph,
+            Some(_) => return None,
+        };
+        let params = match &bytes[1..] {
+            [len, context @ ..] if *len as usize == context.len() => {
+                Self::new(variant, context).ok()?
+            }
+            _ => return None,
+        };
+        // The default parameters must be encoded as no bytes.
+        (params != Self::default()).then_some(params)
+    }
+
+    /// Accepts only 64-byte prehashes for Ed448ph.
+    fn accepts_message(&self, message: &[u8]) -> bool {
+        self.variant != Ed448Variant::Ed448ph || message.len() == PREHASH_LEN
+    }
+}
+
+/// The `serde` representation of [`SigningParameters`], which is validated
+/// when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SigningParametersSerialization {
+    variant: Ed448Variant,
+    context: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SigningParametersSerialization> for SigningParameters {
+    type Error = Error;
+
+    fn try_from(value: SigningParametersSerialization) -> Result<Self, Self::Error> {
+        Self::new(value.variant, &value.context)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SigningParameters> for SigningParametersSerialization {
+    fn from(value: SigningParameters) -> Self {
+        Self {
+            variant: value.variant,
+            context: value.context,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use frost_ed448::{keys::rfc8032, *};
+use rand::thread_rng;
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Shake256,
+};
+
+/// Seeds and public keys from RFC 8032, sections 7.4 (-----blank and
+/// -----1 octet) and 7.5.
+const RFC8032_KEYS: [(&str, &str); 3] = [
+    (
+        "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3\
+         528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+        "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778\
+         edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+    ),
+    (
+        "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463a\
+         fbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+        "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c086\
+         6aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+    ),
+    (
+        "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42\
+         ef7822e0d5104127dc05d6dbefde69e3ab2cec7c867c6e2c49",
+        "259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743\
+         c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880",
+    ),
+];
+
+fn seed(hex: &str) -> [u8; 57] {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+/// The RFC 8032 expanded secret key of `seed`: its 114-byte SHAKE256 hash.
+fn expand(seed: &[u8; 57]) -> [u8; 114] {
+    let mut output = [0u8; 114];
+    Shake256::default()
+        .chain(seed)
+        .finalize_xof_into(&mut output);
+    output
+}
+
+#[test]
+fn check_signing_key_from_seed_rfc8032_vectors() {
+    for (secret_key, public_key) in RFC8032_KEYS {
+        let seed = seed(secret_key);
+        let signing_key = rfc8032::signing_key_from_seed(&seed);
+
+        assert_eq!(
+            hex::encode(VerifyingKey::from(signing_key).serialize()),
+            public_key
+        );
+        assert_eq!(
+            rfc8032::signing_key_from_expanded_secret_key(&expand(&seed)),
+            signing_key
+        );
+    }
+}
+
+#[test]
+fn check_split_seed_rfc8032_vectors() {
+    let mut rng = thread_rng();
+
+    for (secret_key, public_key) in RFC8032_KEYS {
+        let seed = seed(secret_key);
+
+        for (shares, pubkey_package) in [
+            rfc8032::split_seed(&seed, 5, 3, keys::IdentifierList::Default, &mut rng).unwrap(),
+            rfc8032::split_expanded_secret_key(
+                &expand(&seed),
+                5,
+                3,
+                keys::IdentifierList::Default,
+                &mut rng,
+            )
+            .unwrap(),
+        ] {
+            assert_eq!(
+                hex::encode(pubkey_package.verifying_key().serialize()),
+                public_key
+            );
+
+            let key_packages = shares
+                .into_iter()
+                .map(|(identifier, share)| (identifier, share.try_into().unwrap()))
+                .collect();
+            let (target, group_signature, group_pubkey) =
+                frost_core::tests::ciphersuite_generic::check_sign(
+                    3,
+                    key_packages,
+                    &mut rng,
+                    pubkey_package,
+                    b"message".into(),
+                )
+                .unwrap();
+
+            // The signature is valid for the RFC 8032 public key.
+            VerifyingKey::deserialize(hex::decode(public_key).unwrap().try_into().unwrap())
+                .unwrap()
+                .verify(target.message(), &group_signature)
+                .unwrap();
+            assert_eq!(hex::encode(group_pubkey.serialize()), public_key);
+        }
+    }
+}
+
+/// Signatures from RFC 8032, sections 7.4 and 7.5: the index of the key in
+/// [`RFC8032_KEYS`], the message, the context, whether it is an Ed448ph
+/// signature, and the signature.
+const RFC8032_SIGNATURES: [(usize, &str, &str, bool, &str); 5] = [
+    (
+        0,
+        "",
+        "",
+        false,
+        "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f\
+         2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a\
+         9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4db\
+         b61149f05a7363268c71d95808ff2e652600",
+    ),
+    (
+        1,
+        "03",
+        "",
+        false,
+        "26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f435\
+         2541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cb\
+         cee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0f\
+         f3348ab21aa4adafd1d234441cf807c03a00",
+    ),
+    (
+        1,
+        "03",
+        "666f6f",
+        false,
+        "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2\
+         151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da\
+         1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d\
+         5428407e85dcbc98a49155c13764e66c3c00",
+    ),
+    (
+        2,
+        "616263",
+        "",
+        true,
+        "822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae38\
+         1f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd\
+         433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3\
+         ad203df7dc7ce360c3cd3696d9d9fab90f00",
+    ),
+    (
+        2,
+        "616263",
+        "666f6f",
+        true,
+        "c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa48\
+         1065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3\
+         653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab7128\
+         4f8d0704a608c54a6b62d97beb511d132100",
+    ),
+];
+
+#[test]
+fn check_expanded_secret_key_sign_rfc8032_vectors() {
+    for (key, message, context, prehashed, signature) in RFC8032_SIGNATURES {
+        let expanded_secret_key = rfc8032::ExpandedSecretKey::from_seed(&seed(RFC8032_KEYS[key].0));
+        let message = hex::decode(message).unwrap();
+        let context = hex::decode(context).unwrap();
+
+        let sig_target = if prehashed {
+            // Ed448ph signs the 64-byte SHAKE256 hash of the message.
+            let mut prehash = [0u8; 64];
+            Shake256::default()
+                .chain(&message)
+                .finalize_xof_into(&mut prehash);
+            SigningTarget::new(
+                prehash.as_slice(),
+                SigningParameters::ed448ph(&context).unwrap(),
+            )
+        } else {
+            SigningParameters::ed448(&context)
+                .unwrap()
+                .signing_target(&message)
+        };
+
+        assert_eq!(
+            hex::encode(expanded_secret_key.sign(sig_target).unwrap().serialize()),
+            signature
+        );
+    }
+}
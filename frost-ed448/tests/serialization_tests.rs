@@ -2,10 +2,12 @@
 
 mod helpers;
 
+use std::collections::BTreeMap;
+
 use frost_ed448::{
     keys::{
         dkg::{round1, round2},
-        KeyPackage, PublicKeyPackage, SecretShare,
+        GroupDescriptor, KeyPackage, PublicKeyPackage, SecretShare,
     },
     round1::{SigningCommitments, SigningNonces},
     round2::SignatureShare,
@@ -15,10 +17,135 @@ use frost_ed448::{
 use helpers::samples;
 use insta::assert_snapshot;
 
+#[test]
+fn check_signing_nonces_serialization() {
+    let nonces = samples::signing_nonces();
+    let bytes = nonces.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(nonces, SigningNonces::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn check_signing_commitments_serialization() {
+    let commitments = samples::signing_commitments();
+    let bytes = commitments.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        commitments,
+        SigningCommitments::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_signing_package_serialization() {
+    let signing_package = samples::signing_package();
+    let bytes = signing_package.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        signing_package,
+        SigningPackage::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_secret_share_serialization() {
+    let secret_share = samples::secret_share();
+    let bytes = secret_share.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(secret_share, SecretShare::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn check_key_package_serialization() {
+    let key_package = samples::key_package();
+    let bytes = key_package.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(key_package, KeyPackage::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn check_public_key_package_serialization() {
+    let public_key_package = samples::public_key_package();
+    let bytes = public_key_package.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        public_key_package,
+        PublicKeyPackage::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_public_key_package_min_signers_serialization() {
+    let sample = samples::public_key_package();
+    let public_key_package = PublicKeyPackage::new_with_min_signers(
+        sample.verifying_shares().clone(),
+        *sample.verifying_key(),
+        Some(2),
+    );
+    let bytes = public_key_package.serialize().unwrap();
+    let deserialized = PublicKeyPackage::deserialize(&bytes).unwrap();
+    assert_eq!(*deserialized.min_signers(), Some(2));
+    assert_eq!(public_key_package, deserialized);
+}
+
+#[test]
+fn check_public_key_package_commitment_serialization() {
+    let secret_share = samples::secret_share();
+    let public_key_package = PublicKeyPackage::from_commitment(
+        &[*secret_share.identifier()].into(),
+        secret_share.commitment(),
+    )
+    .unwrap();
+    let bytes = public_key_package.serialize().unwrap();
+    let deserialized = PublicKeyPackage::deserialize(&bytes).unwrap();
+    assert_eq!(
+        deserialized.commitment(),
+        &Some(secret_share.commitment().clone())
+    );
+    assert_eq!(public_key_package, deserialized);
+    deserialized.verify().unwrap();
+}
+
+#[test]
+fn check_round1_package_serialization() {
+    let round1_package = samples::round1_package();
+    let bytes = round1_package.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        round1_package,
+        round1::Package::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_round2_package_serialization() {
+    let round2_package = samples::round2_package();
+    let bytes = round2_package.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        round2_package,
+        round2::Package::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_group_descriptor_serialization() {
+    let group_descriptor = samples::group_descriptor();
+    let bytes = group_descriptor.serialize().unwrap();
+    assert_snapshot!(hex::encode(&bytes));
+    assert_eq!(
+        group_descriptor,
+        GroupDescriptor::deserialize(&bytes).unwrap()
+    );
+}
+
+// The legacy encoding (format version 0) is the postcard encoding of the serde
+// representation, which must still be accepted when deserializing.
+
 #[test]
 fn check_signing_nonces_postcard_serialization() {
     let nonces = samples::signing_nonces();
-    let bytes: Vec<_> = nonces.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&nonces).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(nonces, SigningNonces::deserialize(&bytes).unwrap());
 }
@@ -26,7 +153,7 @@ fn check_signing_nonces_postcard_serialization() {
 #[test]
 fn check_signing_commitments_postcard_serialization() {
     let commitments = samples::signing_commitments();
-    let bytes: Vec<_> = commitments.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&commitments).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(
         commitments,
@@ -37,7 +164,7 @@ fn check_signing_commitments_postcard_serialization() {
 #[test]
 fn check_signing_package_postcard_serialization() {
     let signing_package = samples::signing_package();
-    let bytes: Vec<_> = signing_package.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&signing_package).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(
         signing_package,
@@ -55,7 +182,7 @@ fn check_signature_share_postcard_serialization() {
 #[test]
 fn check_secret_share_postcard_serialization() {
     let secret_share = samples::secret_share();
-    let bytes: Vec<_> = secret_share.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&secret_share).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(secret_share, SecretShare::deserialize(&bytes).unwrap());
 }
@@ -63,7 +190,7 @@ fn check_secret_share_postcard_serialization() {
 #[test]
 fn check_key_package_postcard_serialization() {
     let key_package = samples::key_package();
-    let bytes: Vec<_> = key_package.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&key_package).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(key_package, KeyPackage::deserialize(&bytes).unwrap());
 }
@@ -71,7 +198,7 @@ fn check_key_package_postcard_serialization() {
 #[test]
 fn check_public_key_package_postcard_serialization() {
     let public_key_package = samples::public_key_package();
-    let bytes: Vec<_> = public_key_package.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&public_key_package).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(
         public_key_package,
@@ -79,10 +206,39 @@ fn check_public_key_package_postcard_serialization() {
     );
 }
 
+#[test]
+fn check_public_key_package_from_commitment_postcard_serialization() {
+    let secret_share = samples::secret_share();
+    let public_key_package = PublicKeyPackage::from_commitment(
+        &[*secret_share.identifier()].into(),
+        secret_share.commitment(),
+    )
+    .unwrap();
+    let bytes = postcard::to_allocvec(&public_key_package).unwrap();
+    assert_eq!(
+        public_key_package,
+        postcard::from_bytes::<PublicKeyPackage>(&bytes).unwrap()
+    );
+    assert_eq!(
+        public_key_package,
+        PublicKeyPackage::deserialize(&bytes).unwrap()
+    );
+}
+
+#[test]
+fn check_public_key_package_serde_round_trip() {
+    let rng = rand::thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_serde_round_trip::<
+        frost_ed448::Ed448Shake256,
+        _,
+    >(rng);
+}
+
 #[test]
 fn check_round1_package_postcard_serialization() {
     let round1_package = samples::round1_package();
-    let bytes: Vec<_> = round1_package.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&round1_package).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(
         round1_package,
@@ -93,10 +249,175 @@ fn check_round1_package_postcard_serialization() {
 #[test]
 fn check_round2_package_postcard_serialization() {
     let round2_package = samples::round2_package();
-    let bytes: Vec<_> = round2_package.serialize().unwrap();
+    let bytes = postcard::to_allocvec(&round2_package).unwrap();
     assert_snapshot!(hex::encode(&bytes));
     assert_eq!(
         round2_package,
         round2::Package::deserialize(&bytes).unwrap()
     );
 }
+
+// Encodings produced by previous releases, frozen for each format version.
+// They must keep deserializing to the samples after the format changes.
+#[test]
+fn check_serialization_fixtures() {
+    let fixtures: BTreeMap<String, BTreeMap<String, String>> =
+        serde_json::from_str(include_str!("helpers/serialization-fixtures.json")).unwrap();
+    assert!(fixtures.contains_key(&frost_core::serialization::LEGACY_FORMAT_VERSION.to_string()));
+
+    for (version, fixtures) in fixtures {
+        let bytes = |name: &str| hex::decode(&fixtures[name]).unwrap();
+        assert_eq!(
+            SecretShare::deserialize(&bytes("secret_share")).unwrap(),
+            samples::secret_share(),
+            "version {version}"
+        );
+        assert_eq!(
+            KeyPackage::deserialize(&bytes("key_package")).unwrap(),
+            samples::key_package(),
+            "version {version}"
+        );
+        assert_eq!(
+            PublicKeyPackage::deserialize(&bytes("public_key_package")).unwrap(),
+            samples::public_key_package(),
+            "version {version}"
+        );
+        assert_eq!(
+            SigningNonces::deserialize(&bytes("signing_nonces")).unwrap(),
+            samples::signing_nonces(),
+            "version {version}"
+        );
+        assert_eq!(
+            SigningCommitments::deserialize(&bytes("signing_commitments")).unwrap(),
+            samples::signing_commitments(),
+            "version {version}"
+        );
+        assert_eq!(
+            SigningPackage::deserialize(&bytes("signing_package")).unwrap(),
+            samples::signing_package(),
+            "version {version}"
+        );
+        assert_eq!(
+            round1::Package::deserialize(&bytes("round1_package")).unwrap(),
+            samples::round1_package(),
+            "version {version}"
+        );
+        assert_eq!(
+            round2::Package::deserialize(&bytes("round2_package")).unwrap(),
+            samples::round2_package(),
+            "version {version}"
+        );
+        // Group descriptors were added after the legacy format.
+        if let Some(group_descriptor) = fixtures.get("group_descriptor") {
+            assert_eq!(
+                GroupDescriptor::deserialize(&hex::decode(group_descriptor).unwrap()).unwrap(),
+                samples::group_descriptor(),
+                "version {version}"
+            );
+        }
+    }
+}
+
+#[test]
+fn check_package_kind() {
+    use frost_core::serialization::{
+        package_kind, peek_header, short_id, PackageKind, FORMAT_VERSION,
+    };
+    use frost_ed448::Ed448Shake256;
+
+    let packages = [
+        (
+            samples::secret_share().serialize().unwrap(),
+            PackageKind::SecretShare,
+        ),
+        (
+            samples::key_package().serialize().unwrap(),
+            PackageKind::KeyPackage,
+        ),
+        (
+            samples::public_key_package().serialize().unwrap(),
+            PackageKind::PublicKeyPackage,
+        ),
+        (
+            samples::signing_nonces().serialize().unwrap(),
+            PackageKind::SigningNonces,
+        ),
+        (
+            samples::signing_commitments().serialize().unwrap(),
+            PackageKind::SigningCommitments,
+        ),
+        (
+            samples::signing_package().serialize().unwrap(),
+            PackageKind::SigningPackage,
+        ),
+        (
+            samples::round1_package().serialize().unwrap(),
+            PackageKind::DkgRound1Package,
+        ),
+        (
+            samples::round2_package().serialize().unwrap(),
+            PackageKind::DkgRound2Package,
+        ),
+        (
+            samples::group_descriptor().serialize().unwrap(),
+            PackageKind::GroupDescriptor,
+        ),
+    ];
+
+    for (mut bytes, kind) in packages {
+        let header = peek_header(&bytes).unwrap();
+        assert_eq!(header.version, FORMAT_VERSION);
+        assert_eq!(header.short_id, short_id::<Ed448Shake256>());
+        assert_eq!(package_kind::<Ed448Shake256>(&bytes), Ok(kind));
+
+        // The kind is read from the header, without decoding the body.
+        let mut corrupt = bytes[..6].to_vec();
+        corrupt.extend(bytes[6..].iter().map(|_| 0xff));
+        assert_eq!(package_kind::<Ed448Shake256>(&corrupt), Ok(kind));
+        bytes.push(0);
+        assert_eq!(package_kind::<Ed448Shake256>(&bytes), Ok(kind));
+        assert_eq!(
+            KeyPackage::deserialize(&bytes),
+            Err(frost_core::Error::DeserializationError)
+        );
+
+        // Neither are packages of another ciphersuite.
+        bytes[1] ^= 0xff;
+        assert_eq!(
+            package_kind::<Ed448Shake256>(&bytes),
+            Err(frost_core::Error::WrongCiphersuite)
+        );
+        assert_eq!(
+            KeyPackage::deserialize(&bytes),
+            Err(frost_core::Error::WrongCiphersuite)
+        );
+    }
+
+    let mut legacy_bytes = postcard::to_allocvec(&samples::key_package()).unwrap();
+    assert_eq!(
+        package_kind::<Ed448Shake256>(&legacy_bytes),
+        Ok(PackageKind::KeyPackage)
+    );
+
+    // The legacy format does not name the kind, so its packages must decode.
+    legacy_bytes.push(0);
+    assert_eq!(
+        package_kind::<Ed448Shake256>(&legacy_bytes),
+        Err(frost_core::Error::DeserializationError)
+    );
+
+    // Unknown kinds and format versions are rejected.
+    let mut bytes = samples::key_package().serialize().unwrap();
+    bytes[5] = 0xff;
+    assert_eq!(
+        package_kind::<Ed448Shake256>(&bytes),
+        Err(frost_core::Error::DeserializationError)
+    );
+    bytes[0] = FORMAT_VERSION + 1;
+    assert_eq!(
+        package_kind::<Ed448Shake256>(&bytes),
+        Err(frost_core::Error::DeserializationError)
+    );
+
+    assert_eq!(peek_header(&[0, 1, 2]), None);
+}
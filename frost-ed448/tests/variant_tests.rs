@@ -0,0 +1,300 @@
+use frost_core::SigningParameters as _;
+use frost_ed448::*;
+use rand::thread_rng;
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Shake256,
+};
+
+fn ed448(context: &[u8]) -> SigningParameters {
+    SigningParameters::ed448(context).unwrap()
+}
+
+fn ed448ph(context: &[u8]) -> SigningParameters {
+    SigningParameters::ed448ph(context).unwrap()
+}
+
+/// The Ed448ph prehash of `message`: its 64-byte SHAKE256 hash.
+fn prehash(message: &[u8]) -> [u8; 64] {
+    let mut output = [0u8; 64];
+    Shake256::default()
+        .chain(message)
+        .finalize_xof_into(&mut output);
+    output
+}
+
+fn verifying_key(hex: &str) -> VerifyingKey {
+    VerifyingKey::deserialize(hex::decode(hex).unwrap().try_into().unwrap()).unwrap()
+}
+
+fn signature(hex: &str) -> Signature {
+    Signature::deserialize(hex::decode(hex).unwrap().try_into().unwrap()).unwrap()
+}
+
+#[test]
+fn check_signing_parameters_validation() {
+    assert_eq!(SigningParameters::default(), ed448(b""));
+
+    assert_eq!(
+        SigningParameters::ed448(&[0xab; 256]),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        SigningParameters::ed448ph(&[0xab; 256]),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert!(SigningParameters::ed448(&[0xab; 255]).is_ok());
+    assert!(SigningParameters::ed448ph(b"").is_ok());
+}
+
+#[test]
+fn check_signing_parameters_bytes() {
+    for params in [
+        SigningParameters::default(),
+        ed448(b"foo"),
+        ed448ph(b""),
+        ed448ph(&[0xab; 255]),
+    ] {
+        assert_eq!(
+            SigningParameters::from_bytes(&params.to_bytes()),
+            Some(params)
+        );
+    }
+
+    // The default parameters are encoded as no bytes, as when they were `()`.
+    assert!(SigningParameters::default().to_bytes().is_empty());
+    assert_eq!(ed448(b"foo").to_bytes(), b"\x00\x03foo");
+    assert_eq!(ed448ph(b"").to_bytes(), b"\x01\x00");
+
+    for bytes in [
+        &b"\x00\x00"[..],
+        b"\x02\x00",
+        b"\x00\x03fo",
+        b"\x00\x03fooo",
+        b"\x01",
+    ] {
+        assert_eq!(SigningParameters::from_bytes(bytes), None);
+    }
+}
+
+/// Test vector from RFC 8032, section 7.4 (1 octet, with context "foo").
+#[test]
+fn check_ed448_context_rfc8032_vector() {
+    let verifying_key = verifying_key(
+        "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c086\
+         6aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+    );
+    let message = hex::decode("03").unwrap();
+    let signature = signature(
+        "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2\
+         151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da\
+         1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d\
+         5428407e85dcbc98a49155c13764e66c3c00",
+    );
+
+    verifying_key
+        .verify(SigningTarget::new(&message, ed448(b"foo")), &signature)
+        .expect("signature should be valid for its context");
+    verifying_key
+        .verify(SigningTarget::new(&message, ed448(b"bar")), &signature)
+        .expect_err("signature should not be valid for another context");
+    verifying_key
+        .verify(&message, &signature)
+        .expect_err("signature should not be valid with an empty context");
+}
+
+/// Test vectors from RFC 8032, section 7.5 (Ed448ph, without and with
+/// context "foo").
+#[test]
+fn check_ed448ph_rfc8032_vectors() {
+    let verifying_key = verifying_key(
+        "259b71c19f83ef77a7abd26524cbdb3161b590a48f7d17de3ee0ba9c52beb743\
+         c09428a131d6b1b57303d90d8132c276d5ed3d5d01c0f53880",
+    );
+    let prehash = prehash(b"abc");
+
+    for (context, signature) in [
+        (
+            &b""[..],
+            signature(
+                "822f6901f7480f3d5f562c592994d9693602875614483256505600bbc281ae38\
+                 1f54d6bce2ea911574932f52a4e6cadd78769375ec3ffd1b801a0d9b3f4030cd\
+                 433964b6457ea39476511214f97469b57dd32dbc560a9a94d00bff07620464a3\
+                 ad203df7dc7ce360c3cd3696d9d9fab90f00",
+            ),
+        ),
+        (
+            b"foo",
+            signature(
+                "c32299d46ec8ff02b54540982814dce9a05812f81962b649d528095916a2aa48\
+                 1065b1580423ef927ecf0af5888f90da0f6a9a85ad5dc3f280d91224ba9911a3\
+                 653d00e484e2ce232521481c8658df304bb7745a73514cdb9bf3e15784ab7128\
+                 4f8d0704a608c54a6b62d97beb511d132100",
+            ),
+        ),
+    ] {
+        verifying_key
+            .verify(SigningTarget::new(prehash, ed448ph(context)), &signature)
+            .expect("signature should be valid for the prehashed message");
+        verifying_key
+            .verify(SigningTarget::new(prehash, ed448(context)), &signature)
+            .expect_err("signature should not be valid as plain Ed448");
+    }
+}
+
+#[test]
+fn check_ed448_context_sign_with_dkg() {
+    let rng = thread_rng();
+
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Ed448Shake256, _>(
+            rng,
+            SigningTarget::new(b"message", ed448(b"context")),
+        );
+
+    group_pubkey
+        .verify(target.message(), &group_signature)
+        .expect_err("signature should not be valid with an empty context");
+}
+
+#[test]
+fn check_ed448ph_sign_with_dealer() {
+    let rng = thread_rng();
+    let message = b"a large firmware image";
+
+    // The signers only see the prehash of the message.
+    let (_, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Ed448Shake256, _>(
+            rng,
+            SigningTarget::new(prehash(message), ed448ph(b"firmware")),
+        );
+
+    group_pubkey
+        .verify(
+            SigningTarget::new(prehash(message), ed448(b"firmware")),
+            &group_signature,
+        )
+        .expect_err("signature should not be valid as plain Ed448");
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_variant_signing_package_serialization() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let mut one = [0; 57];
+    one[0] = 1;
+    let signing_share = keys::SigningShare::deserialize(one).unwrap();
+    let (_, commitments) = round1::commit(&signing_share, &mut rng);
+    let identifier = Identifier::try_from(1).unwrap();
+
+    for params in [
+        SigningParameters::default(),
+        ed448(b"context"),
+        ed448ph(b""),
+    ] {
+        let signing_package = SigningPackage::new(
+            BTreeMap::from([(identifier, commitments)]),
+            params.signing_target(b"message"),
+        );
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            SigningPackage::deserialize(&bytes).unwrap(),
+            signing_package
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_signing_parameters_serde() {
+    let params = ed448ph(b"foo");
+    let json = serde_json::to_string(&params).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SigningParameters>(&json).unwrap(),
+        params
+    );
+
+    let invalid = format!(r#"{{"variant":"Ed448","context":{:?}}}"#, [0u8; 256]);
+    assert!(serde_json::from_str::<SigningParameters>(&invalid).is_err());
+}
+
+#[test]
+fn check_ed448ph_signing_target() {
+    let message = b"a large firmware image";
+
+    let target = ed448ph(b"firmware").signing_target(message);
+    assert_eq!(target.message(), &prehash(message));
+    let target = ed448(b"firmware").signing_target(message);
+    assert_eq!(target.message(), message);
+
+    assert!(!ed448ph(b"").accepts_message(message));
+    assert!(ed448ph(b"").accepts_message(&prehash(message)));
+    assert!(ed448(b"firmware").accepts_message(message));
+}
+
+#[test]
+fn check_ed448ph_sign_rejects_unhashed_message() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let target = SigningTarget::new(b"message", ed448ph(b""));
+
+    assert_eq!(
+        signing_key.try_sign(&mut rng, target.clone()),
+        Err(Error::InvalidSigningParameters)
+    );
+    assert_eq!(
+        signing_key.sign_deterministic(target),
+        Err(Error::InvalidSigningParameters)
+    );
+}
+
+#[test]
+fn check_ed448ph_signing_package_rejects_unhashed_message() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let (shares, pubkeys) =
+        keys::generate_with_dealer(2, 2, keys::IdentifierList::Default, &mut rng).unwrap();
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    let mut key_packages = BTreeMap::new();
+    for (identifier, share) in shares {
+        let key_package = keys::KeyPackage::try_from(share).unwrap();
+        let (signing_nonces, signing_commitments) =
+            round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(identifier, signing_nonces);
+        commitments.insert(identifier, signing_commitments);
+        key_packages.insert(identifier, key_package);
+    }
+
+    // The package can be built, but neither signed nor aggregated.
+    let signing_package = SigningPackage::new(
+        commitments.clone(),
+        SigningTarget::new(b"message", ed448ph(b"")),
+    );
+    for (identifier, key_package) in &key_packages {
+        assert_eq!(
+            round2::sign(&signing_package, &nonces[identifier], key_package),
+            Err(Error::InvalidSigningParameters)
+        );
+    }
+
+    let prehashed_package = SigningPackage::new(
+        commitments,
+        SigningTarget::new(prehash(b"message"), ed448ph(b"")),
+    );
+    let signature_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let share = round2::sign(&prehashed_package, &nonces[identifier], key_package).unwrap();
+            (*identifier, share)
+        })
+        .collect();
+    aggregate(&prehashed_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(
+        aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::InvalidSigningParameters)
+    );
+}
@@ -5,7 +5,10 @@
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::sync::OnceLock;
 
 use frost_rerandomized::RandomizedCiphersuite;
 use p256::{
@@ -19,7 +22,7 @@ use p256::{
 use rand_core::{CryptoRng, RngCore};
 use sha2::{Digest, Sha256};
 
-use frost_core as frost;
+use frost_core::{self as frost, GeneratorTable};
 
 #[cfg(test)]
 mod tests;
@@ -112,6 +115,11 @@ impl Group for P256Group {
         ProjectivePoint::GENERATOR
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        static TABLE: OnceLock<GeneratorTable<P256Group>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::new).mul(scalar)
+    }
+
     fn serialize(element: &Self::Element) -> Self::Serialization {
         let mut fixed_serialized = [0; 33];
         let serialized_point = element.to_encoded_point(true);
@@ -194,6 +202,8 @@ impl Ciphersuite for P256Sha256 {
 
     type SigningParameters = ();
 
+    type VerificationMode = ();
+
     /// H1 for FROST(P-256, SHA-256)
     ///
     /// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.4-2.2.2.1
@@ -262,7 +272,7 @@ type P = P256Sha256;
 pub type Identifier = frost::Identifier<P>;
 /// FROST(P-256, SHA-256) keys, key generation, key shares.
 pub mod keys {
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     use super::*;
 
@@ -354,6 +364,10 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<P>;
 
+    /// A description of a FROST(P-256, SHA-256) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<P>;
+
     pub mod dkg;
     pub mod repairable;
 }
@@ -421,6 +435,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(P-256, SHA-256) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(P-256, SHA-256) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<P>;
+
+    /// Encrypt FROST(P-256, SHA-256) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(P-256, SHA-256) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(P-256, SHA-256).
 pub type Signature = frost_core::Signature<P>;
 
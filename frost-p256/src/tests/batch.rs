@@ -16,9 +16,44 @@ fn check_bad_batch_verify() {
     frost_core::tests::batch::bad_batch_verify::<P256Sha256, _>(rng);
 }
 
+#[test]
+fn check_large_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::large_batch_verify::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_and_locate() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify_and_locate::<P256Sha256, _>(rng);
+}
+
 #[test]
 fn empty_batch_verify() {
     let rng = thread_rng();
 
     frost_core::tests::batch::empty_batch_verify::<P256Sha256, _>(rng);
 }
+
+#[test]
+fn check_batch_verify_from_encodings() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify_from_encodings::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_vartime_multiscalar_mul() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::check_vartime_multiscalar_mul::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_pippenger_window_sizes() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::check_pippenger_window_sizes::<P256Sha256, _>(rng);
+}
@@ -6,8 +6,8 @@ use frost_core::{round1::Nonce, Ciphersuite, Element, Group, Scalar};
 use frost_p256::{
     keys::{
         dkg::{round1, round2},
-        KeyPackage, PublicKeyPackage, SecretShare, SigningShare, VerifiableSecretSharingCommitment,
-        VerifyingShare,
+        GroupDescriptor, KeyPackage, PublicKeyPackage, SecretShare, SigningShare,
+        VerifiableSecretSharingCommitment, VerifyingShare,
     },
     round1::{NonceCommitment, SigningCommitments, SigningNonces},
     round2::SignatureShare,
@@ -124,3 +124,28 @@ pub fn round2_package() -> round2::Package {
 
     round2::Package::new(signing_share)
 }
+
+/// Generate a sample GroupDescriptor.
+pub fn group_descriptor() -> GroupDescriptor {
+    let participants = BTreeMap::from([
+        (42u16.try_into().unwrap(), "alice".to_string()),
+        (43u16.try_into().unwrap(), "bob".to_string()),
+    ]);
+    let serialized_element1 = <C as Ciphersuite>::Group::serialize(&element1());
+    let serialized_element2 = <C as Ciphersuite>::Group::serialize(&element2());
+    let commitment = VerifiableSecretSharingCommitment::deserialize(vec![
+        serialized_element1,
+        serialized_element2,
+    ])
+    .unwrap();
+
+    GroupDescriptor::new(
+        2,
+        2,
+        participants,
+        commitment,
+        1_700_000_000,
+        "sample group".to_string(),
+    )
+    .unwrap()
+}
@@ -8,6 +8,13 @@ fn check_zero_key_fails() {
     frost_core::tests::ciphersuite_generic::check_zero_key_fails::<P256Sha256>();
 }
 
+#[test]
+fn check_generator_mul() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_generator_mul::<P256Sha256, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dkg() {
     let rng = thread_rng();
@@ -67,6 +74,37 @@ fn check_rts() {
     frost_core::tests::repairable::check_rts::<P256Sha256, _>(rng);
 }
 
+#[test]
+fn check_group_descriptor() {
+    let rng = thread_rng();
+
+    frost_core::tests::group_descriptor::check_group_descriptor::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_public_key_package_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verify::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_deterministic() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_deterministic::<P256Sha256, _>(
+        rng,
+        b"message".into(),
+    );
+}
+
+#[test]
+fn check_keystore() {
+    let rng = thread_rng();
+
+    frost_core::tests::keystore::check_keystore::<P256Sha256, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dealer() {
     let rng = thread_rng();
@@ -0,0 +1,59 @@
+use rand::thread_rng;
+
+use crate::*;
+
+#[test]
+fn check_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_bad_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::bad_batch_verify::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_large_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::large_batch_verify::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_and_locate() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify_and_locate::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn empty_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::empty_batch_verify::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_batch_verify_from_encodings() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify_from_encodings::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_vartime_multiscalar_mul() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::check_vartime_multiscalar_mul::<P384Sha384, _>(rng);
+}
+
+#[test]
+fn check_pippenger_window_sizes() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::check_pippenger_window_sizes::<P384Sha384, _>(rng);
+}
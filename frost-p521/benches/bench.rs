@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::thread_rng;
+
+use frost_p521::*;
+
+fn bench_p521_batch_verify(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify::<P521Sha512, _>(c, "p521", &mut rng);
+}
+
+fn bench_p521_sign(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_sign::<P521Sha512, _>(c, "p521", &mut rng);
+}
+
+criterion_group!(benches, bench_p521_batch_verify, bench_p521_sign);
+criterion_main!(benches);
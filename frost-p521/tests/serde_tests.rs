@@ -0,0 +1,658 @@
+#![cfg(feature = "serde")]
+
+mod helpers;
+
+use frost_p521::{
+    keys::{
+        dkg::{round1, round2},
+        KeyPackage, PublicKeyPackage, SecretShare,
+    },
+    round1::SigningCommitments,
+    round2::SignatureShare,
+    SigningPackage,
+};
+
+use helpers::samples;
+
+#[test]
+fn check_signing_commitments_serialization() {
+    let commitments = samples::signing_commitments();
+
+    let json = serde_json::to_string_pretty(&commitments).unwrap();
+    println!("{}", json);
+
+    let decoded_commitments: SigningCommitments = serde_json::from_str(&json).unwrap();
+    assert!(commitments == decoded_commitments);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+      }"#;
+    let decoded_commitments: SigningCommitments = serde_json::from_str(json).unwrap();
+    assert!(commitments == decoded_commitments);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<SigningCommitments>(invalid_json).is_err());
+
+    // Wrong ciphersuite
+    let invalid_json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST(Wrong, SHA-512)"
+      },
+      "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+      "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+    }"#;
+    assert!(serde_json::from_str::<SigningCommitments>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "foo": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+      }"#;
+    assert!(serde_json::from_str::<SigningCommitments>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "foo": "0000000000000000000000000000000000000000000000000000000000000000",
+        "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+      }"#;
+    assert!(serde_json::from_str::<SigningCommitments>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST(Ed25519, SHA-512)"
+        },
+        "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d",
+        "extra": 1
+      }"#;
+    assert!(serde_json::from_str::<SigningCommitments>(invalid_json).is_err());
+}
+
+#[test]
+fn check_signing_package_serialization() {
+    let signing_package = samples::signing_package();
+
+    let json = serde_json::to_string_pretty(&signing_package).unwrap();
+    println!("{}", json);
+
+    let decoded_signing_package: SigningPackage = serde_json::from_str(&json).unwrap();
+    assert!(signing_package == decoded_signing_package);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<SigningPackage>(invalid_json).is_err());
+
+    let json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "signing_commitments": {
+        "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": {
+          "header": {
+            "version": 0,
+            "ciphersuite": "FROST-P521-SHA512-v1"
+          },
+          "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+          "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+        }
+      },
+      "sig_target": {
+        "message": "68656c6c6f20776f726c64"
+      }
+    }"#;
+    let decoded_signing_package: SigningPackage = serde_json::from_str(json).unwrap();
+    assert!(signing_package == decoded_signing_package);
+
+    // Invalid identifier
+    let invalid_json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "signing_commitments": {
+        "0000000000000000000000000000000000000000000000000000000000000000": {
+          "header": {
+            "version": 0,
+            "ciphersuite": "FROST-P521-SHA512-v1"
+          },
+          "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+          "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+        }
+      },
+      "sig_target": {
+        "message": "68656c6c6f20776f726c64"
+      }
+    }"#;
+    assert!(serde_json::from_str::<SigningPackage>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "signing_commitments": {
+        "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": {
+          "header": {
+            "version": 0,
+            "ciphersuite": "FROST-P521-SHA512-v1"
+          },
+          "foo": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+          "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+        }
+      },
+      "sig_target": {
+        "message": "68656c6c6f20776f726c64"
+      }
+    }"#;
+    assert!(serde_json::from_str::<SigningPackage>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "signing_commitments": {
+        "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": {
+          "header": {
+            "version": 0,
+            "ciphersuite": "FROST-P521-SHA512-v1"
+          },
+          "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+        }
+      },
+      "sig_target": {
+        "message": "68656c6c6f20776f726c64"
+      }
+    }"#;
+    assert!(serde_json::from_str::<SigningPackage>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "signing_commitments": {
+        "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": {
+          "header": {
+            "version": 0,
+            "ciphersuite": "FROST-P521-SHA512-v1"
+          },
+          "hiding": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+          "binding": "0200433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d"
+        }
+      },
+      "sig_target": {
+        "message": "68656c6c6f20776f726c64"
+      },
+      "extra": 1
+    }
+    "#;
+    assert!(serde_json::from_str::<SigningPackage>(invalid_json).is_err());
+}
+
+#[test]
+fn check_signature_share_serialization() {
+    let signature_share = samples::signature_share();
+
+    let json = serde_json::to_string_pretty(&signature_share).unwrap();
+    println!("{}", json);
+
+    let decoded_signature_share: SignatureShare = serde_json::from_str(&json).unwrap();
+    assert!(signature_share == decoded_signature_share);
+
+    let json = r#"{
+      "header": {
+        "version": 0,
+        "ciphersuite": "FROST-P521-SHA512-v1"
+      },
+      "share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+    }"#;
+    let decoded_commitments: SignatureShare = serde_json::from_str(json).unwrap();
+    assert!(signature_share == decoded_commitments);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<SignatureShare>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "foo": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+      }"#;
+    assert!(serde_json::from_str::<SignatureShare>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        }
+      }"#;
+    assert!(serde_json::from_str::<SignatureShare>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "extra": 1
+      }"#;
+    assert!(serde_json::from_str::<SignatureShare>(invalid_json).is_err());
+}
+
+#[test]
+fn check_secret_share_serialization() {
+    let secret_share = samples::secret_share();
+
+    let json = serde_json::to_string_pretty(&secret_share).unwrap();
+    println!("{}", json);
+
+    let decoded_secret_share: SecretShare = serde_json::from_str(&json).unwrap();
+    assert!(secret_share == decoded_secret_share);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+      }"#;
+    let decoded_secret_share: SecretShare = serde_json::from_str(json).unwrap();
+    assert!(secret_share == decoded_secret_share);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<SecretShare>(invalid_json).is_err());
+
+    // Invalid identifier
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "0000000000000000000000000000000000000000000000000000000000000000",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+      }"#;
+    assert!(serde_json::from_str::<SecretShare>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "foo": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+      }"#;
+    assert!(serde_json::from_str::<SecretShare>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+      }"#;
+    assert!(serde_json::from_str::<SecretShare>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+        "extra": 1,
+      }"#;
+    assert!(serde_json::from_str::<SecretShare>(invalid_json).is_err());
+}
+
+#[test]
+fn check_key_package_serialization() {
+    let key_package = samples::key_package();
+
+    let json = serde_json::to_string_pretty(&key_package).unwrap();
+    println!("{}", json);
+
+    let decoded_key_package: KeyPackage = serde_json::from_str(&json).unwrap();
+    assert!(key_package == decoded_key_package);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "verifying_share": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "min_signers": 2
+      }"#;
+    let decoded_key_package: KeyPackage = serde_json::from_str(json).unwrap();
+    assert!(key_package == decoded_key_package);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+
+    // Invalid identifier
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "0000000000000000000000000000000000000000000000000000000000000000",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "verifying_share": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "min_signers": 2
+      }"#;
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "foo": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "verifying_share": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+      }"#;
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "verifying_share": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+      }"#;
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "verifying_share": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "extra_field": 1
+      }"#;
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+
+    // Invalid version
+    let invalid_json = r#"{
+        "header": {
+          "version": 1,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "identifier": "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a",
+        "secret_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "public": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "group_public": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "min_signers": 2
+      }"#;
+    assert!(serde_json::from_str::<KeyPackage>(invalid_json).is_err());
+}
+
+#[test]
+fn check_public_key_package_serialization() {
+    let public_key_package = samples::public_key_package();
+
+    let json = serde_json::to_string_pretty(&public_key_package).unwrap();
+    println!("{}", json);
+
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(&json).unwrap();
+    assert!(public_key_package == decoded_public_key_package);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "verifying_shares": {
+          "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        },
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+      }"#;
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
+    assert!(public_key_package == decoded_public_key_package);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
+
+    // Invalid identifier
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "verifying_shares": {
+          "0000000000000000000000000000000000000000000000000000000000000000": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        },
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+      }"#;
+    assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "verifying_shares": {
+          "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        },
+        "foo": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+      }"#;
+    assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "verifying_shares": {
+          "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        }
+      }"#;
+    assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "verifying_shares": {
+          "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002a": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        },
+        "verifying_key": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+        "extra": 1
+      }"#;
+    assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
+}
+
+#[test]
+fn check_public_key_package_from_commitment_serialization() {
+    let secret_share = samples::secret_share();
+    let public_key_package = PublicKeyPackage::from_commitment(
+        &[*secret_share.identifier()].into(),
+        secret_share.commitment(),
+    )
+    .unwrap();
+
+    let json = serde_json::to_string_pretty(&public_key_package).unwrap();
+    println!("{}", json);
+
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(&json).unwrap();
+    assert!(public_key_package == decoded_public_key_package);
+}
+
+#[test]
+fn check_round1_package_serialization() {
+    let round1_package = samples::round1_package();
+
+    let json = serde_json::to_string_pretty(&round1_package).unwrap();
+    println!("{}", json);
+
+    let decoded_round1_package: round1::Package = serde_json::from_str(&json).unwrap();
+    assert!(round1_package == decoded_round1_package);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ],
+        "proof_of_knowledge": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd6600aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+      }"#;
+    let decoded_round1_package: round1::Package = serde_json::from_str(json).unwrap();
+    assert!(round1_package == decoded_round1_package);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<round1::Package>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ],
+        "foo": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd6600aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+      }"#;
+    assert!(serde_json::from_str::<round1::Package>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ]
+      }"#;
+    assert!(serde_json::from_str::<round1::Package>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "commitment": [
+          "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ],
+        "proof_of_knowledge": "0200c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd6600aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "extra": 1
+      }"#;
+    assert!(serde_json::from_str::<round1::Package>(invalid_json).is_err());
+}
+
+#[test]
+fn check_round2_package_serialization() {
+    let round2_package = samples::round2_package();
+
+    let json = serde_json::to_string_pretty(&round2_package).unwrap();
+    println!("{}", json);
+
+    let decoded_round2_package: round2::Package = serde_json::from_str(&json).unwrap();
+    assert!(round2_package == decoded_round2_package);
+
+    let json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+      }"#;
+    let decoded_round2_package: round2::Package = serde_json::from_str(json).unwrap();
+    assert!(round2_package == decoded_round2_package);
+
+    let invalid_json = "{}";
+    assert!(serde_json::from_str::<round2::Package>(invalid_json).is_err());
+
+    // Invalid field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "foo": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae"
+      }"#;
+    assert!(serde_json::from_str::<round2::Package>(invalid_json).is_err());
+
+    // Missing field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        }
+      }"#;
+    assert!(serde_json::from_str::<round2::Package>(invalid_json).is_err());
+
+    // Extra field
+    let invalid_json = r#"{
+        "header": {
+          "version": 0,
+          "ciphersuite": "FROST-P521-SHA512-v1"
+        },
+        "signing_share": "00aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa8c5d782813fba87792a9955c2fd033745693c9892d8896d3a3e7a925f85bd76ae",
+        "extra": 1
+      }"#;
+    assert!(serde_json::from_str::<round2::Package>(invalid_json).is_err());
+}
@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::thread_rng;
+
+use frost_redjubjub::*;
+
+fn bench_redjubjub_batch_verify(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify::<JubjubBlake2b512, _>(c, "redjubjub", &mut rng);
+}
+
+fn bench_redjubjub_sign(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_sign::<JubjubBlake2b512, _>(c, "redjubjub", &mut rng);
+}
+
+criterion_group!(benches, bench_redjubjub_batch_verify, bench_redjubjub_sign);
+criterion_main!(benches);
@@ -0,0 +1,490 @@
+#![allow(non_snake_case)]
+#![deny(missing_docs)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc = document_features::document_features!()]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use frost_rerandomized::RandomizedCiphersuite;
+use group::{
+    ff::{Field as FFField, PrimeField},
+    GroupEncoding,
+};
+use jubjub::{ExtendedPoint, Scalar};
+use rand_core::{CryptoRng, RngCore};
+
+use frost_core::{self as frost, GeneratorTable};
+
+#[cfg(test)]
+mod tests;
+
+// Re-exports in our public API
+pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError};
+pub use rand_core;
+
+/// An error.
+pub type Error = frost_core::Error<JubjubBlake2b512>;
+
+/// An implementation of the FROST(Jubjub, BLAKE2b-512) ciphersuite scalar field.
+#[derive(Clone, Copy)]
+pub struct JubjubScalarField;
+
+impl Field for JubjubScalarField {
+    type Scalar = Scalar;
+
+    type Serialization = [u8; 32];
+
+    fn zero() -> Self::Scalar {
+        Scalar::ZERO
+    }
+
+    fn one() -> Self::Scalar {
+        Scalar::ONE
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError> {
+        // [`jubjub::Scalar`]'s Eq/PartialEq does a constant-time comparison using
+        // `ConstantTimeEq`
+        if *scalar == <Self as Field>::zero() {
+            Err(FieldError::InvalidZeroScalar)
+        } else {
+            Ok(scalar.invert().unwrap())
+        }
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Scalar {
+        Scalar::random(rng)
+    }
+
+    fn serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        // `to_repr()` is little-endian for `jubjub::Scalar`, which is the
+        // encoding used by RedJubjub.
+        scalar.to_repr()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Scalar, FieldError> {
+        match Scalar::from_repr(*buf).into() {
+            Some(s) => Ok(s),
+            None => Err(FieldError::MalformedScalar),
+        }
+    }
+
+    fn little_endian_serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        Self::serialize(scalar)
+    }
+}
+
+/// The encoding of the Sapling spend authorization basepoint,
+/// `FindGroupHash^J("Zcash_G_", "")`.
+///
+/// See the [Zcash protocol specification, section 5.4.7.1][spec].
+///
+/// [spec]: https://zips.z.cash/protocol/protocol.pdf#concretespendauthsig
+const SPEND_AUTH_BASEPOINT_BYTES: [u8; 32] = [
+    48, 181, 242, 170, 173, 50, 86, 48, 188, 221, 219, 206, 77, 103, 101, 109, 5, 253, 28, 194,
+    208, 55, 187, 83, 117, 182, 233, 109, 158, 1, 161, 215,
+];
+
+/// An implementation of the FROST(Jubjub, BLAKE2b-512) ciphersuite group.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct JubjubGroup;
+
+impl Group for JubjubGroup {
+    type Field = JubjubScalarField;
+
+    type Element = ExtendedPoint;
+
+    /// The encoding of a Jubjub point: the little-endian encoding of its v
+    /// coordinate, with the sign of its u coordinate in the highest bit.
+    type Serialization = [u8; 32];
+
+    /// Jubjub has a cofactor of 8. Elements are checked to be in the prime
+    /// order subgroup when deserialized, but the cofactor is still used by
+    /// the verification equation, as required by the Zcash specification.
+    fn cofactor() -> <Self::Field as Field>::Scalar {
+        Scalar::from(8)
+    }
+
+    fn identity() -> Self::Element {
+        ExtendedPoint::identity()
+    }
+
+    fn generator() -> Self::Element {
+        static GENERATOR: OnceLock<ExtendedPoint> = OnceLock::new();
+        *GENERATOR.get_or_init(|| {
+            ExtendedPoint::from_bytes(&SPEND_AUTH_BASEPOINT_BYTES)
+                .expect("the basepoint encoding is valid")
+        })
+    }
+
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        static TABLE: OnceLock<GeneratorTable<JubjubGroup>> = OnceLock::new();
+        TABLE.get_or_init(GeneratorTable::new).mul(scalar)
+    }
+
+    fn serialize(element: &Self::Element) -> Self::Serialization {
+        element.to_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Element, GroupError> {
+        // `from_bytes()` rejects non-canonical encodings, as required by
+        // ZIP 216.
+        match Option::<ExtendedPoint>::from(ExtendedPoint::from_bytes(buf)) {
+            Some(point) => {
+                if point == Self::identity() {
+                    Err(GroupError::InvalidIdentityElement)
+                } else if point.is_torsion_free().into() {
+                    Ok(point)
+                } else {
+                    Err(GroupError::InvalidNonPrimeOrderElement)
+                }
+            }
+            None => Err(GroupError::MalformedElement),
+        }
+    }
+}
+
+/// BLAKE2b-512 with the given 16-byte personalization.
+fn hash_to_array(personalization: &[u8; 16], inputs: &[&[u8]]) -> [u8; 64] {
+    let mut state = blake2b_simd::Params::new()
+        .hash_length(64)
+        .personal(personalization)
+        .to_state();
+    for i in inputs {
+        state.update(i);
+    }
+    *state.finalize().as_array()
+}
+
+/// H^star from RedJubjub: BLAKE2b-512 with the given personalization,
+/// reduced to a scalar.
+fn hash_to_scalar(personalization: &[u8; 16], inputs: &[&[u8]]) -> Scalar {
+    Scalar::from_bytes_wide(&hash_to_array(personalization, inputs))
+}
+
+/// The personalization of H^star in RedJubjub, which is used for the
+/// signature challenge.
+///
+/// See the [Zcash protocol specification, section 5.4.7][spec].
+///
+/// [spec]: https://zips.z.cash/protocol/protocol.pdf#concretereddsa
+const CHALLENGE_PERSONALIZATION: &[u8; 16] = b"Zcash_RedJubjubH";
+
+/// Ciphersuite identifier, as used by the [`reddsa`] crate.
+///
+/// [`reddsa`]: https://crates.io/crates/reddsa
+const CONTEXT_STRING: &str = "FROST(Jubjub, BLAKE2b-512)";
+
+/// An implementation of the FROST(Jubjub, BLAKE2b-512) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct JubjubBlake2b512;
+
+/// The ciphersuite-specific signing parameters which are fed into
+/// signing code to ensure correctly compliant signatures are computed.
+pub type SigningParameters = ();
+
+/// The message target which the group's signature should commit to. Includes
+/// a message byte vector, and a set of ciphersuite-specific parameters.
+pub type SigningTarget = frost_core::SigningTarget<JubjubBlake2b512>;
+
+impl Ciphersuite for JubjubBlake2b512 {
+    const ID: &'static str = CONTEXT_STRING;
+
+    type Group = JubjubGroup;
+
+    type HashOutput = [u8; 64];
+
+    type SignatureSerialization = [u8; 64];
+
+    type SigningParameters = ();
+
+    type VerificationMode = ();
+
+    /// H1 for FROST(Jubjub, BLAKE2b-512)
+    fn H1(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(b"FROST_RedJubjubR", &[m])
+    }
+
+    /// H2 for FROST(Jubjub, BLAKE2b-512)
+    ///
+    /// This is the RedJubjub H^star, so that the group signatures are
+    /// RedJubjub signatures.
+    fn H2(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(CHALLENGE_PERSONALIZATION, &[m])
+    }
+
+    /// H3 for FROST(Jubjub, BLAKE2b-512)
+    fn H3(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(b"FROST_RedJubjubN", &[m])
+    }
+
+    /// H4 for FROST(Jubjub, BLAKE2b-512)
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(b"FROST_RedJubjubM", &[m])
+    }
+
+    /// H5 for FROST(Jubjub, BLAKE2b-512)
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(b"FROST_RedJubjubC", &[m])
+    }
+
+    /// HDKG for FROST(Jubjub, BLAKE2b-512)
+    fn HDKG(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(b"FROST_RedJubjubD", &[m]))
+    }
+
+    /// HID for FROST(Jubjub, BLAKE2b-512)
+    fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(b"FROST_RedJubjubI", &[m]))
+    }
+}
+
+impl RandomizedCiphersuite for JubjubBlake2b512 {
+    fn hash_randomizer(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(b"FROST_RedJubjubA", &[m]))
+    }
+}
+
+// Shorthand alias for the ciphersuite
+type J = JubjubBlake2b512;
+
+/// A FROST(Jubjub, BLAKE2b-512) participant identifier.
+pub type Identifier = frost::Identifier<J>;
+/// FROST(Jubjub, BLAKE2b-512) keys, key generation, key shares.
+pub mod keys {
+    use alloc::collections::BTreeMap;
+
+    use super::*;
+
+    /// The identifier list to use when generating key shares.
+    pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, J>;
+
+    /// Allows all participants' keys to be generated using a central, trusted
+    /// dealer.
+    pub fn generate_with_dealer<RNG: RngCore + CryptoRng>(
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        mut rng: RNG,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, &mut rng)
+    }
+
+    /// Splits an existing key into FROST shares.
+    ///
+    /// This is identical to [`generate_with_dealer`] but receives an existing key
+    /// instead of generating a fresh one. This is useful in scenarios where
+    /// the key needs to be generated externally or must be derived from e.g. a
+    /// seed phrase.
+    pub fn split<R: RngCore + CryptoRng>(
+        secret: &SigningKey,
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        rng: &mut R,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
+    }
+
+    /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
+    ///
+    /// This can be used if for some reason the original key must be restored; e.g.
+    /// if threshold signing is not required anymore.
+    ///
+    /// This is NOT required to sign with FROST; the whole point of FROST is being
+    /// able to generate signatures only using the shares, without having to
+    /// reconstruct the original key.
+    ///
+    /// The caller is responsible for providing at least `min_signers` shares;
+    /// if less than that is provided, a different key will be returned.
+    pub fn reconstruct(secret_shares: &[KeyPackage]) -> Result<SigningKey, Error> {
+        frost::keys::reconstruct(secret_shares)
+    }
+
+    /// Secret and public key material generated by a dealer performing
+    /// [`generate_with_dealer`].
+    ///
+    /// # Security
+    ///
+    /// To derive a FROST(Jubjub, BLAKE2b-512) keypair, the receiver of the [`SecretShare`] *must* call
+    /// .into(), which under the hood also performs validation.
+    pub type SecretShare = frost::keys::SecretShare<J>;
+
+    /// A secret scalar value representing a signer's share of the group secret.
+    pub type SigningShare = frost::keys::SigningShare<J>;
+
+    /// A public group element that represents a single signer's public verification share.
+    pub type VerifyingShare = frost::keys::VerifyingShare<J>;
+
+    /// A FROST(Jubjub, BLAKE2b-512) keypair, which can be generated either by a trusted dealer or using
+    /// a DKG.
+    ///
+    /// When using a central dealer, [`SecretShare`]s are distributed to
+    /// participants, who then perform verification, before deriving
+    /// [`KeyPackage`]s, which they store to later use during signing.
+    pub type KeyPackage = frost::keys::KeyPackage<J>;
+
+    /// Public data that contains all the signers' public keys as well as the
+    /// group public key.
+    ///
+    /// Used for verification purposes before publishing a signature.
+    pub type PublicKeyPackage = frost::keys::PublicKeyPackage<J>;
+
+    /// Contains the commitments to the coefficients for our secret polynomial _f_,
+    /// used to generate participants' key shares.
+    ///
+    /// [`VerifiableSecretSharingCommitment`] contains a set of commitments to the coefficients (which
+    /// themselves are scalars) for a secret polynomial f, where f is used to
+    /// generate each ith participant's key share f(i). Participants use this set of
+    /// commitments to perform verifiable secret sharing.
+    ///
+    /// Note that participants MUST be assured that they have the *same*
+    /// [`VerifiableSecretSharingCommitment`], either by performing pairwise comparison, or by using
+    /// some agreed-upon public location for publication, where each participant can
+    /// ensure that they received the correct (and same) value.
+    pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<J>;
+
+    /// A description of a FROST(Jubjub, BLAKE2b-512) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<J>;
+
+    pub mod dkg;
+    pub mod repairable;
+}
+
+/// FROST(Jubjub, BLAKE2b-512) Round 1 functionality and types.
+pub mod round1 {
+    use crate::keys::SigningShare;
+
+    use super::*;
+
+    /// Comprised of FROST(Jubjub, BLAKE2b-512) hiding and binding nonces.
+    ///
+    /// Note that [`SigningNonces`] must be used *only once* for a signing
+    /// operation; re-using nonces will result in leakage of a signer's long-lived
+    /// signing key.
+    pub type SigningNonces = frost::round1::SigningNonces<J>;
+
+    /// Published by each participant in the first round of the signing protocol.
+    ///
+    /// This step can be batched if desired by the implementation. Each
+    /// SigningCommitment can be used for exactly *one* signature.
+    pub type SigningCommitments = frost::round1::SigningCommitments<J>;
+
+    /// A commitment to a signing nonce share.
+    pub type NonceCommitment = frost::round1::NonceCommitment<J>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Generates the signing nonces and commitments to be used in the signing
+    /// operation.
+    pub fn commit<RNG>(secret: &SigningShare, rng: &mut RNG) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit::<J, RNG>(secret, rng)
+    }
+}
+
+/// Generated by the coordinator of the signing operation and distributed to
+/// each signing party.
+pub type SigningPackage = frost::SigningPackage<J>;
+
+/// FROST(Jubjub, BLAKE2b-512) Round 2 functionality and types, for signature share generation.
+pub mod round2 {
+    use super::*;
+
+    /// A FROST(Jubjub, BLAKE2b-512) participant's signature share, which the Coordinator will aggregate with all other signer's
+    /// shares into the joint signature.
+    pub type SignatureShare = frost::round2::SignatureShare<J>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Receives the message to be signed and a set of signing commitments and a set
+    /// of randomizing commitments to be used in that signing operation, including
+    /// that for this participant.
+    ///
+    /// Assumes the participant has already determined which nonce corresponds with
+    /// the commitment that was assigned by the coordinator in the SigningPackage.
+    pub fn sign(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign(signing_package, signer_nonces, key_package)
+    }
+}
+
+/// FROST(Jubjub, BLAKE2b-512) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(Jubjub, BLAKE2b-512) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<J>;
+
+    /// Encrypt FROST(Jubjub, BLAKE2b-512) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(Jubjub, BLAKE2b-512) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
+/// A Schnorr signature on FROST(Jubjub, BLAKE2b-512).
+pub type Signature = frost_core::Signature<J>;
+
+/// Verifies each FROST(Jubjub, BLAKE2b-512) participant's signature share, and if all are valid,
+/// aggregates the shares into a signature to publish.
+///
+/// Resulting signature is compatible with verification of a plain Schnorr
+/// signature.
+///
+/// This operation is performed by a coordinator that can communicate with all
+/// the signing participants before publishing the final signature. The
+/// coordinator can be one of the participants or a semi-trusted third party
+/// (who is trusted to not perform denial of service attacks, but does not learn
+/// any secret information). Note that because the coordinator is trusted to
+/// report misbehaving parties in order to avoid publishing an invalid
+/// signature, if the coordinator themselves is a signer and misbehaves, they
+/// can avoid that step. However, at worst, this results in a denial of
+/// service attack due to publishing an invalid signature.
+pub fn aggregate(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate(signing_package, signature_shares, pubkeys)
+}
+
+/// A signing key for a Schnorr signature on FROST(Jubjub, BLAKE2b-512).
+pub type SigningKey = frost_core::SigningKey<J>;
+
+/// A valid verifying key for Schnorr signatures on FROST(Jubjub, BLAKE2b-512).
+pub type VerifyingKey = frost_core::VerifyingKey<J>;
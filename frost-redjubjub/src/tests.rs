@@ -0,0 +1,5 @@
+mod batch;
+mod coefficient_commitment;
+mod deserialize;
+mod proptests;
+mod vss_commitment;
@@ -0,0 +1,86 @@
+use crate::*;
+
+#[test]
+fn check_deserialize_non_canonical() {
+    let encoded_generator = <JubjubBlake2b512 as Ciphersuite>::Group::serialize(
+        &<JubjubBlake2b512 as Ciphersuite>::Group::generator(),
+    );
+
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_generator);
+    assert!(r.is_ok());
+
+    // This is v = q, which is non-canonical.
+    let encoded_point =
+        hex::decode("01000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+
+    // This is the point of order 2 (u = 0, v = -1) with the sign bit set,
+    // which is rejected by ZIP 216.
+    let encoded_point =
+        hex::decode("00000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7edf3")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+}
+
+#[test]
+fn check_deserialize_identity() {
+    // The identity is (u = 0, v = 1).
+    let mut encoded_identity = [0u8; 32];
+    encoded_identity[0] = 1;
+
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_identity);
+    assert_eq!(r, Err(GroupError::InvalidIdentityElement));
+}
+
+#[test]
+fn check_deserialize_non_prime_order() {
+    // This is the point of order 2 (u = 0, v = -1).
+    let encoded_point =
+        hex::decode("00000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::InvalidNonPrimeOrderElement));
+
+    // Adding it to the generator gives a point which is not torsion free.
+    let point = <JubjubBlake2b512 as Ciphersuite>::Group::generator()
+        + jubjub::ExtendedPoint::from_bytes(&encoded_point).unwrap();
+    let r = <JubjubBlake2b512 as Ciphersuite>::Group::deserialize(
+        &<JubjubBlake2b512 as Ciphersuite>::Group::serialize(&point),
+    );
+    assert_eq!(r, Err(GroupError::InvalidNonPrimeOrderElement));
+}
+
+#[test]
+fn check_generator_is_spend_auth_basepoint() {
+    use reddsa::sapling::SpendAuth;
+
+    // The verifying key of the signing key 1 is the basepoint.
+    let signing_key = reddsa::SigningKey::<SpendAuth>::try_from(Scalar::ONE.to_repr()).unwrap();
+    let verifying_key = reddsa::VerificationKey::from(&signing_key);
+
+    assert_eq!(
+        <JubjubBlake2b512 as Ciphersuite>::Group::serialize(
+            &<JubjubBlake2b512 as Ciphersuite>::Group::generator()
+        ),
+        <[u8; 32]>::from(verifying_key)
+    );
+}
+
+#[test]
+fn check_cofactor() {
+    let generator = <JubjubBlake2b512 as Ciphersuite>::Group::generator();
+
+    assert_eq!(
+        generator * <JubjubBlake2b512 as Ciphersuite>::Group::cofactor(),
+        generator.mul_by_cofactor()
+    );
+}
@@ -0,0 +1,62 @@
+use frost_redjubjub::*;
+use rand::thread_rng;
+use reddsa::sapling::SpendAuth;
+
+/// Check that `signature` is a valid RedJubjub signature with `verifying_key`
+/// according to the `reddsa` crate.
+fn verify_with_reddsa(msg: &[u8], signature: &Signature, verifying_key: &VerifyingKey) {
+    let verifying_key =
+        reddsa::VerificationKey::<SpendAuth>::try_from(verifying_key.serialize()).unwrap();
+    let signature = reddsa::Signature::<SpendAuth>::from(signature.serialize());
+    verifying_key.verify(msg, &signature).unwrap();
+}
+
+#[test]
+fn check_sign_with_dealer_interoperability() {
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<JubjubBlake2b512, _>(
+            thread_rng(),
+            b"message".into(),
+        );
+
+    verify_with_reddsa(target.message(), &group_signature, &group_pubkey);
+}
+
+#[test]
+fn check_sign_with_dkg_interoperability() {
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<JubjubBlake2b512, _>(
+            thread_rng(),
+            b"message".into(),
+        );
+
+    verify_with_reddsa(target.message(), &group_signature, &group_pubkey);
+}
+
+#[test]
+fn check_randomized_sign_with_dealer_interoperability() {
+    let (msg, group_signature, randomized_group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<JubjubBlake2b512, _>(
+            thread_rng(),
+        );
+
+    verify_with_reddsa(&msg, &group_signature, &randomized_group_pubkey);
+}
+
+#[test]
+fn check_sign_with_reddsa_key() {
+    let mut rng = thread_rng();
+
+    // Split a key generated by `reddsa`.
+    let reddsa_signing_key = reddsa::SigningKey::<SpendAuth>::new(&mut rng);
+    let signing_key = SigningKey::deserialize(<[u8; 32]>::from(reddsa_signing_key)).unwrap();
+    let verifying_key = VerifyingKey::from(signing_key);
+    assert_eq!(
+        verifying_key.serialize(),
+        <[u8; 32]>::from(reddsa::VerificationKey::from(&reddsa_signing_key))
+    );
+
+    let msg = b"message";
+    let signature = signing_key.sign(&mut rng, msg);
+    verify_with_reddsa(msg, &signature, &verifying_key);
+}
@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::thread_rng;
+
+use frost_redpallas::*;
+
+fn bench_redpallas_batch_verify(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify::<PallasBlake2b512, _>(c, "redpallas", &mut rng);
+}
+
+fn bench_redpallas_sign(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_sign::<PallasBlake2b512, _>(c, "redpallas", &mut rng);
+}
+
+criterion_group!(benches, bench_redpallas_batch_verify, bench_redpallas_sign);
+criterion_main!(benches);
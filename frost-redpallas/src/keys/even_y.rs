@@ -0,0 +1,140 @@
+//! Conversion of keys to a group verifying key with an even Y coordinate.
+//!
+//! Orchard requires the spend validating key `ak` to have an even Y
+//! coordinate (i.e. the sign bit of its encoding must be zero). Since negating
+//! the group secret negates the group verifying key, any key can be converted
+//! to one with an even Y coordinate by negating all secret shares, verifying
+//! shares, commitments and the group verifying key.
+//!
+//! [`generate_with_dealer()`](super::generate_with_dealer),
+//! [`split()`](super::split) and [`dkg::part3()`](super::dkg::part3) already
+//! return converted keys. Note that this means that the group verifying key
+//! returned by [`split()`](super::split) is the negation of the verifying key
+//! of the given [`SigningKey`](crate::SigningKey) if the latter has an odd Y
+//! coordinate. This trait is useful to convert keys generated elsewhere, e.g.
+//! with a previous version of this crate.
+//!
+//! Rerandomized verifying keys do not need to have an even Y coordinate.
+
+use alloc::collections::BTreeMap;
+
+use frost_core::keys::CoefficientCommitment;
+use pasta_curves::pallas;
+
+use crate::{Group, PallasGroup, VerifyingKey};
+
+use super::{
+    KeyPackage, PublicKeyPackage, SecretShare, VerifiableSecretSharingCommitment, VerifyingShare,
+};
+
+/// Conversion of keys to a group verifying key with an even Y coordinate.
+pub trait EvenY {
+    /// Return if the group verifying key has an even Y coordinate.
+    fn has_even_y(&self) -> bool;
+
+    /// Convert the keys so that the group verifying key has an even Y
+    /// coordinate, negating them if it does not.
+    ///
+    /// `is_even` can be given if it was already determined with
+    /// [`EvenY::has_even_y()`], e.g. from the [`PublicKeyPackage`] of the
+    /// group, to avoid checking it again.
+    fn into_even_y(self, is_even: Option<bool>) -> Self;
+}
+
+fn negate_verifying_key(verifying_key: &VerifyingKey) -> VerifyingKey {
+    VerifyingKey::new(-verifying_key.to_element())
+}
+
+fn negate_commitment(
+    commitment: &VerifiableSecretSharingCommitment,
+) -> VerifiableSecretSharingCommitment {
+    VerifiableSecretSharingCommitment::new(
+        commitment
+            .coefficients()
+            .iter()
+            .map(|coefficient| CoefficientCommitment::new(-coefficient.value()))
+            .collect(),
+    )
+}
+
+fn is_even(element: &pallas::Point) -> bool {
+    !PallasGroup::y_is_odd(element)
+}
+
+impl EvenY for PublicKeyPackage {
+    fn has_even_y(&self) -> bool {
+        is_even(&self.verifying_key().to_element())
+    }
+
+    fn into_even_y(self, is_even: Option<bool>) -> Self {
+        if is_even.unwrap_or_else(|| self.has_even_y()) {
+            return self;
+        }
+        match self.commitment() {
+            // Recompute everything from the negated commitment.
+            Some(commitment) => {
+                let identifiers = self.verifying_shares().keys().copied().collect();
+                PublicKeyPackage::from_commitment(&identifiers, &negate_commitment(commitment))
+                    .expect("the negation of a valid commitment is valid")
+            }
+            None => {
+                let verifying_shares: BTreeMap<_, _> = self
+                    .verifying_shares()
+                    .iter()
+                    .map(|(identifier, share)| {
+                        (*identifier, VerifyingShare::new(-share.to_element()))
+                    })
+                    .collect();
+                PublicKeyPackage::new_with_min_signers(
+                    verifying_shares,
+                    negate_verifying_key(self.verifying_key()),
+                    *self.min_signers(),
+                )
+            }
+        }
+    }
+}
+
+impl EvenY for KeyPackage {
+    fn has_even_y(&self) -> bool {
+        is_even(&self.verifying_key().to_element())
+    }
+
+    fn into_even_y(self, is_even: Option<bool>) -> Self {
+        if is_even.unwrap_or_else(|| self.has_even_y()) {
+            return self;
+        }
+        let mut signing_share = *self.signing_share();
+        signing_share.negate();
+        KeyPackage::new(
+            *self.identifier(),
+            signing_share,
+            VerifyingShare::new(-self.verifying_share().to_element()),
+            negate_verifying_key(self.verifying_key()),
+            *self.min_signers(),
+        )
+    }
+}
+
+impl EvenY for SecretShare {
+    fn has_even_y(&self) -> bool {
+        self.commitment()
+            .coefficients()
+            .first()
+            .map(|coefficient| is_even(&coefficient.value()))
+            .unwrap_or(true)
+    }
+
+    fn into_even_y(self, is_even: Option<bool>) -> Self {
+        if is_even.unwrap_or_else(|| self.has_even_y()) {
+            return self;
+        }
+        let mut signing_share = *self.signing_share();
+        signing_share.negate();
+        SecretShare::new(
+            *self.identifier(),
+            signing_share,
+            negate_commitment(self.commitment()),
+        )
+    }
+}
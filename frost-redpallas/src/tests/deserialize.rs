@@ -0,0 +1,58 @@
+use crate::*;
+
+#[test]
+fn check_deserialize_non_canonical() {
+    let mut encoded_generator = <PallasBlake2b512 as Ciphersuite>::Group::serialize(
+        &<PallasBlake2b512 as Ciphersuite>::Group::generator(),
+    );
+
+    let r = <PallasBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_generator);
+    assert!(r.is_ok());
+
+    // Flipping the sign bit gives the encoding of the negation, which is valid.
+    encoded_generator[31] ^= 0x80;
+    let r = <PallasBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_generator);
+    assert_eq!(
+        r,
+        Ok(-<PallasBlake2b512 as Ciphersuite>::Group::generator())
+    );
+
+    // This is x = p, which is non-canonical.
+    let encoded_point =
+        hex::decode("01000000ed302d991bf94c09fc98462200000000000000000000000000000040")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <PallasBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+}
+
+#[test]
+fn check_deserialize_identity() {
+    let encoded_identity = [0u8; 32];
+
+    let r = <PallasBlake2b512 as Ciphersuite>::Group::deserialize(&encoded_identity);
+    assert_eq!(r, Err(GroupError::InvalidIdentityElement));
+}
+
+#[test]
+fn check_generator_is_spend_auth_basepoint() {
+    use pasta_curves::{arithmetic::CurveExt, pallas};
+
+    assert_eq!(
+        <PallasBlake2b512 as Ciphersuite>::Group::generator(),
+        pallas::Point::hash_to_curve("z.cash:Orchard")(b"G")
+    );
+}
+
+#[test]
+fn check_y_is_odd() {
+    let generator = <PallasBlake2b512 as Ciphersuite>::Group::generator();
+    let is_odd = <PallasBlake2b512 as Ciphersuite>::Group::y_is_odd(&generator);
+
+    // Negating a point negates its Y coordinate, which flips its parity.
+    assert_ne!(
+        <PallasBlake2b512 as Ciphersuite>::Group::y_is_odd(&-generator),
+        is_odd
+    );
+}
@@ -0,0 +1,160 @@
+use frost_redpallas::{
+    keys::{even_y::EvenY, IdentifierList, KeyPackage, PublicKeyPackage},
+    *,
+};
+use rand::thread_rng;
+use reddsa::orchard::SpendAuth;
+
+/// Check that `signature` is a valid RedPallas signature with `verifying_key`
+/// according to the `reddsa` crate.
+fn verify_with_reddsa(msg: &[u8], signature: &Signature, verifying_key: &VerifyingKey) {
+    let verifying_key =
+        reddsa::VerificationKey::<SpendAuth>::try_from(verifying_key.serialize()).unwrap();
+    let signature = reddsa::Signature::<SpendAuth>::from(signature.serialize());
+    verifying_key.verify(msg, &signature).unwrap();
+}
+
+fn has_even_y(verifying_key: &VerifyingKey) -> bool {
+    !PallasGroup::y_is_odd(&verifying_key.to_element())
+}
+
+#[test]
+fn check_sign_with_dealer_interoperability() {
+    for _ in 0..16 {
+        let (target, group_signature, group_pubkey) =
+            frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<PallasBlake2b512, _>(
+                thread_rng(),
+                b"message".into(),
+            );
+
+        assert!(has_even_y(&group_pubkey));
+        verify_with_reddsa(target.message(), &group_signature, &group_pubkey);
+    }
+}
+
+#[test]
+fn check_sign_with_dkg_interoperability() {
+    for _ in 0..16 {
+        let (target, group_signature, group_pubkey) =
+            frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<PallasBlake2b512, _>(
+                thread_rng(),
+                b"message".into(),
+            );
+
+        assert!(has_even_y(&group_pubkey));
+        verify_with_reddsa(target.message(), &group_signature, &group_pubkey);
+    }
+}
+
+#[test]
+fn check_randomized_sign_with_dealer_interoperability() {
+    let (msg, group_signature, randomized_group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<PallasBlake2b512, _>(
+            thread_rng(),
+        );
+
+    verify_with_reddsa(&msg, &group_signature, &randomized_group_pubkey);
+}
+
+#[test]
+fn check_split_negates_odd_key() {
+    let mut rng = thread_rng();
+
+    // Find a key with an odd Y coordinate.
+    let signing_key = loop {
+        let signing_key = SigningKey::new(&mut rng);
+        if !has_even_y(&VerifyingKey::from(signing_key)) {
+            break signing_key;
+        }
+    };
+
+    let (shares, pubkey_package) =
+        keys::split(&signing_key, 5, 3, IdentifierList::Default, &mut rng).unwrap();
+
+    assert!(pubkey_package.has_even_y());
+    assert_eq!(
+        pubkey_package.verifying_key().to_element(),
+        -VerifyingKey::from(signing_key).to_element()
+    );
+    pubkey_package.verify().unwrap();
+
+    let key_packages: Vec<KeyPackage> = shares
+        .into_values()
+        .map(|share| {
+            assert!(share.has_even_y());
+            share.try_into().unwrap()
+        })
+        .collect();
+    assert_eq!(keys::reconstruct(&key_packages).unwrap().serialize(), {
+        let mut negated = keys::SigningShare::new(signing_key.to_scalar());
+        negated.negate();
+        negated.serialize()
+    });
+}
+
+#[test]
+fn check_into_even_y() {
+    let mut rng = thread_rng();
+
+    // Negate the keys, which have an even Y coordinate, to get keys with an
+    // odd one.
+    let (shares, pubkey_package) =
+        keys::generate_with_dealer(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let shares: Vec<_> = shares
+        .into_values()
+        .map(|share| share.into_even_y(Some(false)))
+        .collect();
+    let pubkey_package = pubkey_package.into_even_y(Some(false));
+    assert!(!pubkey_package.has_even_y());
+    let key_packages: Vec<KeyPackage> = shares
+        .iter()
+        .map(|share| KeyPackage::try_from(share.clone()).unwrap())
+        .collect();
+    assert!(shares.iter().all(|share| !share.has_even_y()));
+    assert!(key_packages
+        .iter()
+        .all(|key_package| !key_package.has_even_y()));
+
+    let even_pubkey_package = pubkey_package.clone().into_even_y(None);
+    assert!(even_pubkey_package.has_even_y());
+    assert_eq!(
+        even_pubkey_package.verifying_key().to_element(),
+        -pubkey_package.verifying_key().to_element()
+    );
+    even_pubkey_package.verify().unwrap();
+
+    // A package without a commitment is converted too.
+    let legacy_pubkey_package = PublicKeyPackage::new_with_min_signers(
+        pubkey_package.verifying_shares().clone(),
+        *pubkey_package.verifying_key(),
+        *pubkey_package.min_signers(),
+    )
+    .into_even_y(None);
+    assert_eq!(
+        legacy_pubkey_package.verifying_shares(),
+        even_pubkey_package.verifying_shares()
+    );
+    assert_eq!(
+        legacy_pubkey_package.verifying_key(),
+        even_pubkey_package.verifying_key()
+    );
+
+    for (share, key_package) in shares.into_iter().zip(key_packages) {
+        let even_key_package = key_package.into_even_y(None);
+        assert!(even_key_package.has_even_y());
+        assert_eq!(
+            KeyPackage::try_from(share.into_even_y(None)).unwrap(),
+            even_key_package
+        );
+        assert_eq!(
+            even_pubkey_package.verifying_shares()[even_key_package.identifier()],
+            *even_key_package.verifying_share()
+        );
+    }
+
+    // Keys that already have an even Y coordinate are left unchanged.
+    assert_eq!(
+        even_pubkey_package.clone().into_even_y(None),
+        even_pubkey_package
+    );
+}
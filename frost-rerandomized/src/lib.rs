@@ -10,11 +10,14 @@
 //!   [`frost::round2::SignatureShare`] back to the Coordinator;
 //! - The Coordinator should then call [`aggregate`].
 #![allow(non_snake_case)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 #[cfg(any(test, feature = "test-impl"))]
 pub mod tests;
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use derive_getters::Getters;
 pub use frost_core;
@@ -88,6 +91,9 @@ impl<C: Ciphersuite> Randomize<C> for PublicKeyPackage<C> {
     ///
     /// It's recommended to use [`aggregate`] directly which already handles
     /// the public key package randomization.
+    ///
+    /// The commitment of the package, if any, is not carried over to the
+    /// randomized package, which is only meant to be used for aggregation.
     fn randomize(&self, randomized_params: &RandomizedParams<C>) -> Result<Self, Error<C>>
     where
         Self: Sized,
@@ -106,9 +112,10 @@ impl<C: Ciphersuite> Randomize<C> for PublicKeyPackage<C> {
             })
             .collect();
 
-        Ok(PublicKeyPackage::new(
+        Ok(PublicKeyPackage::new_with_min_signers(
             randomized_verifying_shares,
             randomized_params.randomized_verifying_key,
+            *self.min_signers(),
         ))
     }
 }
@@ -291,7 +298,7 @@ where
         group_verifying_key: &VerifyingKey<C>,
         randomizer: Randomizer<C>,
     ) -> Self {
-        let randomizer_element = <C::Group as Group>::generator() * randomizer.0;
+        let randomizer_element = <C::Group as Group>::generator_mul(&randomizer.0);
         let verifying_key_element = group_verifying_key.to_element();
         let randomized_verifying_key_element = verifying_key_element + randomizer_element;
         let randomized_verifying_key = VerifyingKey::<C>::new(randomized_verifying_key_element);
@@ -2,13 +2,15 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
 
 use curve25519_dalek::{
     constants::RISTRETTO_BASEPOINT_POINT,
     ristretto::{CompressedRistretto, RistrettoPoint},
     scalar::Scalar,
-    traits::Identity,
+    traits::{Identity, VartimeMultiscalarMul},
 };
 use frost_rerandomized::RandomizedCiphersuite;
 use rand_core::{CryptoRng, RngCore};
@@ -96,6 +98,17 @@ impl Group for RistrettoGroup {
         RISTRETTO_BASEPOINT_POINT
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        RistrettoPoint::mul_base(scalar)
+    }
+
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        RistrettoPoint::vartime_multiscalar_mul(scalars, elements)
+    }
+
     fn serialize(element: &Self::Element) -> Self::Serialization {
         element.compress().to_bytes()
     }
@@ -160,6 +173,8 @@ impl Ciphersuite for Ristretto255Sha512 {
 
     type SigningParameters = SigningParameters;
 
+    type VerificationMode = ();
+
     /// H1 for FROST(ristretto255, SHA-512)
     ///
     /// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.2-2.2.2.1
@@ -224,7 +239,7 @@ pub type Identifier = frost::Identifier<R>;
 /// FROST(ristretto255, SHA-512) keys, key generation, key shares.
 pub mod keys {
     use super::*;
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     /// The identifier list to use when generating key shares.
     pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, R>;
@@ -314,6 +329,10 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<R>;
 
+    /// A description of a FROST(ristretto255, SHA-512) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<R>;
+
     pub mod dkg;
     pub mod repairable;
 }
@@ -381,6 +400,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(ristretto255, SHA-512) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(ristretto255, SHA-512) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<R>;
+
+    /// Encrypt FROST(ristretto255, SHA-512) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(ristretto255, SHA-512) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(ristretto255, SHA-512).
 pub type Signature = frost_core::Signature<R>;
 
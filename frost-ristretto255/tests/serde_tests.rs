@@ -521,6 +521,22 @@ fn check_public_key_package_serialization() {
     assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
 }
 
+#[test]
+fn check_public_key_package_from_commitment_serialization() {
+    let secret_share = samples::secret_share();
+    let public_key_package = PublicKeyPackage::from_commitment(
+        &[*secret_share.identifier()].into(),
+        secret_share.commitment(),
+    )
+    .unwrap();
+
+    let json = serde_json::to_string_pretty(&public_key_package).unwrap();
+    println!("{}", json);
+
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(&json).unwrap();
+    assert!(public_key_package == decoded_public_key_package);
+}
+
 #[test]
 fn check_round1_package_serialization() {
     let round1_package = samples::round1_package();
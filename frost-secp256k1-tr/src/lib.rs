@@ -4,8 +4,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, vec, vec::Vec};
 
 use frost_rerandomized::RandomizedCiphersuite;
 use k256::elliptic_curve::ops::Reduce;
@@ -14,6 +17,7 @@ use k256::{
         bigint::U256,
         group::prime::PrimeCurveAffine,
         hash2curve::{hash_to_field, ExpandMsgXmd},
+        ops::{LinearCombinationExt, MulByGenerator},
         point::AffineCoordinates,
         sec1::{FromEncodedPoint, ToEncodedPoint},
         Field as FFField, PrimeField,
@@ -122,6 +126,23 @@ impl Group for Secp256K1Group {
         ProjectivePoint::GENERATOR
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        ProjectivePoint::mul_by_generator(scalar)
+    }
+
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        assert_eq!(scalars.len(), elements.len());
+        let points_and_scalars: Vec<_> = elements
+            .iter()
+            .copied()
+            .zip(scalars.iter().copied())
+            .collect();
+        ProjectivePoint::lincomb_ext(points_and_scalars.as_slice())
+    }
+
     fn y_is_odd(element: &Self::Element) -> bool {
         element.to_affine().y_is_odd().into()
     }
@@ -265,7 +286,27 @@ pub struct SigningParameters {
     pub tapscript_merkle_root: Option<Vec<u8>>,
 }
 
-impl frost_core::SigningParameters for SigningParameters {}
+impl frost_core::SigningParameters for SigningParameters {
+    /// Encodes the parameters as a single `0x00` byte if there is no
+    /// `tapscript_merkle_root`, or as a `0x01` byte followed by the root.
+    fn to_bytes(&self) -> Vec<u8> {
+        match &self.tapscript_merkle_root {
+            None => vec![0],
+            Some(root) => [&[1], root.as_slice()].concat(),
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let tapscript_merkle_root = match bytes.split_first()? {
+            (0, []) => None,
+            (1, root) => Some(root.to_vec()),
+            _ => return None,
+        };
+        Some(SigningParameters {
+            tapscript_merkle_root,
+        })
+    }
+}
 
 impl Ciphersuite for Secp256K1Sha256 {
     const ID: &'static str = CONTEXT_STRING;
@@ -278,6 +319,8 @@ impl Ciphersuite for Secp256K1Sha256 {
 
     type SigningParameters = SigningParameters;
 
+    type VerificationMode = ();
+
     /// H1 for FROST(secp256k1, SHA-256)
     ///
     /// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.5-2.2.2.1
@@ -348,6 +391,34 @@ impl Ciphersuite for Secp256K1Sha256 {
         Challenge::from_scalar(S::H2(&preimage[..]))
     }
 
+    /// Derives the nonce as in BIP340, with `aux` as the auxiliary random
+    /// data (32 zero bytes if it is empty), so that signatures match those of
+    /// other BIP340 implementations given the same auxiliary data.
+    fn deterministic_nonce(
+        secret: &Scalar,
+        verifying_key: &VerifyingKey,
+        aux: &[u8],
+        sig_target: &SigningTarget,
+    ) -> Scalar {
+        let aux = if aux.is_empty() { &[0; 32][..] } else { aux };
+        let mut t = Secp256K1ScalarField::serialize(secret);
+        for (t, a) in t
+            .iter_mut()
+            .zip(tagged_hash("BIP0340/aux").chain_update(aux).finalize())
+        {
+            *t ^= a;
+        }
+        let tweaked_pk = tweaked_public_key(
+            verifying_key,
+            sig_target.sig_params().tapscript_merkle_root.as_ref(),
+        );
+        let mut hasher = tagged_hash("BIP0340/nonce");
+        hasher.update(t);
+        hasher.update(tweaked_pk.to_affine().x());
+        hasher.update(sig_target.message());
+        hasher_to_scalar(hasher)
+    }
+
     /// Finalizes the signature by negating it depending on whether
     /// the group [`VerifyingKey`] is even or odd parity.
     fn aggregate_sig_finalize(
@@ -568,7 +639,7 @@ pub type Identifier = frost::Identifier<S>;
 /// FROST(secp256k1, SHA-256) keys, key generation, key shares.
 pub mod keys {
     use super::*;
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     /// The identifier list to use when generating key shares.
     pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, S>;
@@ -658,6 +729,10 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<S>;
 
+    /// A description of a FROST(secp256k1, SHA-256) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<S>;
+
     pub mod dkg;
     pub mod repairable;
 }
@@ -725,6 +800,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(secp256k1, SHA-256) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(secp256k1, SHA-256) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<S>;
+
+    /// Encrypt FROST(secp256k1, SHA-256) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(secp256k1, SHA-256) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(secp256k1, SHA-256).
 pub type Signature = frost_core::Signature<S>;
 
@@ -87,3 +87,66 @@ fn check_tweaked_sign_with_dealer_and_identifiers() {
         ),
     );
 }
+
+#[test]
+fn check_batch_verify_agrees_with_bip340() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(signing_key);
+    let message = b"message";
+
+    let signing_target = SigningTarget::new(
+        message,
+        SigningParameters {
+            tapscript_merkle_root: Some(vec![]),
+        },
+    );
+    let signature = signing_key.sign(&mut rng, signing_target.clone());
+
+    // BIP340 signatures only commit to the x-coordinate of R, so a signature
+    // with the negated R must verify as well.
+    let negated_signature = Signature::new(-*signature.R(), *signature.z());
+
+    let mut batch = frost_core::batch::Verifier::new();
+    for sig in [signature, negated_signature] {
+        verifying_key
+            .verify(signing_target.clone(), &sig)
+            .expect("signature should be valid");
+        let item = frost_core::batch::Item::new(verifying_key, sig, signing_target.clone());
+        item.clone()
+            .verify_single()
+            .expect("signature should be valid");
+        batch.queue(item);
+    }
+    batch
+        .verify(&mut rng)
+        .expect("batch with tweaked signatures should be valid");
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_tweaked_signing_package_serialization() {
+    use std::collections::BTreeMap;
+
+    let mut rng = thread_rng();
+    let signing_share = keys::SigningShare::new(k256::Scalar::ONE);
+    let (_, commitments) = round1::commit(&signing_share, &mut rng);
+    let identifier = Identifier::try_from(1).unwrap();
+
+    for tapscript_merkle_root in [None, Some(vec![]), Some(vec![0xab; 32])] {
+        let signing_package = SigningPackage::new(
+            BTreeMap::from([(identifier, commitments)]),
+            SigningTarget::new(
+                b"message",
+                SigningParameters {
+                    tapscript_merkle_root,
+                },
+            ),
+        );
+        let bytes = signing_package.serialize().unwrap();
+        assert_eq!(
+            SigningPackage::deserialize(&bytes).unwrap(),
+            signing_package
+        );
+    }
+}
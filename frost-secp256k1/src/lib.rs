@@ -4,14 +4,18 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![doc = include_str!("../README.md")]
 #![doc = document_features::document_features!()]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::collections::BTreeMap;
+extern crate alloc;
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, vec::Vec};
 
 use frost_rerandomized::RandomizedCiphersuite;
 use k256::{
     elliptic_curve::{
         group::prime::PrimeCurveAffine,
         hash2curve::{hash_to_field, ExpandMsgXmd},
+        ops::{LinearCombinationExt, MulByGenerator},
         sec1::{FromEncodedPoint, ToEncodedPoint},
         Field as FFField, PrimeField,
     },
@@ -112,6 +116,23 @@ impl Group for Secp256K1Group {
         ProjectivePoint::GENERATOR
     }
 
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        ProjectivePoint::mul_by_generator(scalar)
+    }
+
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        assert_eq!(scalars.len(), elements.len());
+        let points_and_scalars: Vec<_> = elements
+            .iter()
+            .copied()
+            .zip(scalars.iter().copied())
+            .collect();
+        ProjectivePoint::lincomb_ext(points_and_scalars.as_slice())
+    }
+
     fn serialize(element: &Self::Element) -> Self::Serialization {
         let mut fixed_serialized = [0; 33];
         let serialized_point = element.to_affine().to_encoded_point(true);
@@ -194,6 +215,8 @@ impl Ciphersuite for Secp256K1Sha256 {
 
     type SigningParameters = ();
 
+    type VerificationMode = ();
+
     /// H1 for FROST(secp256k1, SHA-256)
     ///
     /// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.5-2.2.2.1
@@ -263,7 +286,7 @@ pub type Identifier = frost::Identifier<S>;
 /// FROST(secp256k1, SHA-256) keys, key generation, key shares.
 pub mod keys {
     use super::*;
-    use std::collections::BTreeMap;
+    use alloc::collections::BTreeMap;
 
     /// The identifier list to use when generating key shares.
     pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, S>;
@@ -353,6 +376,10 @@ pub mod keys {
     /// ensure that they received the correct (and same) value.
     pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<S>;
 
+    /// A description of a FROST(secp256k1, SHA-256) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<S>;
+
     pub mod dkg;
     pub mod repairable;
 }
@@ -420,6 +447,43 @@ pub mod round2 {
     }
 }
 
+/// FROST(secp256k1, SHA-256) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(secp256k1, SHA-256) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<S>;
+
+    /// Encrypt FROST(secp256k1, SHA-256) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(secp256k1, SHA-256) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
 /// A Schnorr signature on FROST(secp256k1, SHA-256).
 pub type Signature = frost_core::Signature<S>;
 
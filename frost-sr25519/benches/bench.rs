@@ -0,0 +1,19 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::thread_rng;
+
+use frost_sr25519::*;
+
+fn bench_sr25519_batch_verify(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_batch_verify::<Sr25519Sha512, _>(c, "sr25519", &mut rng);
+}
+
+fn bench_sr25519_sign(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_sign::<Sr25519Sha512, _>(c, "sr25519", &mut rng);
+}
+
+criterion_group!(benches, bench_sr25519_batch_verify, bench_sr25519_sign);
+criterion_main!(benches);
@@ -0,0 +1,550 @@
+#![allow(non_snake_case)]
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    scalar::Scalar,
+    traits::{Identity, VartimeMultiscalarMul},
+};
+use frost_rerandomized::RandomizedCiphersuite;
+use merlin::Transcript;
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512};
+
+use frost_core as frost;
+
+#[cfg(test)]
+mod tests;
+
+// Re-exports in our public API
+pub use frost_core::{
+    serde, Challenge, Ciphersuite, Element, Field, FieldError, Group, GroupError,
+};
+pub use rand_core;
+
+/// An error.
+pub type Error = frost_core::Error<Sr25519Sha512>;
+
+/// An implementation of the FROST(sr25519, SHA-512) ciphersuite scalar field.
+#[derive(Clone, Copy)]
+pub struct Sr25519ScalarField;
+
+impl Field for Sr25519ScalarField {
+    type Scalar = Scalar;
+
+    type Serialization = [u8; 32];
+
+    fn zero() -> Self::Scalar {
+        Scalar::ZERO
+    }
+
+    fn one() -> Self::Scalar {
+        Scalar::ONE
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError> {
+        // [`curve25519_dalek::scalar::Scalar`]'s Eq/PartialEq does a constant-time comparison using
+        // `ConstantTimeEq`
+        if *scalar == <Self as Field>::zero() {
+            Err(FieldError::InvalidZeroScalar)
+        } else {
+            Ok(scalar.invert())
+        }
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Scalar {
+        Scalar::random(rng)
+    }
+
+    fn serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        scalar.to_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Scalar, FieldError> {
+        match Scalar::from_canonical_bytes(*buf).into() {
+            Some(s) => Ok(s),
+            None => Err(FieldError::MalformedScalar),
+        }
+    }
+
+    fn little_endian_serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        Self::serialize(scalar)
+    }
+}
+
+/// An implementation of the FROST(sr25519, SHA-512) ciphersuite group.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Sr25519Group;
+
+impl Group for Sr25519Group {
+    type Field = Sr25519ScalarField;
+
+    type Element = RistrettoPoint;
+
+    type Serialization = [u8; 32];
+
+    fn cofactor() -> <Self::Field as Field>::Scalar {
+        Scalar::ONE
+    }
+
+    fn identity() -> Self::Element {
+        RistrettoPoint::identity()
+    }
+
+    fn generator() -> Self::Element {
+        RISTRETTO_BASEPOINT_POINT
+    }
+
+    fn generator_mul(scalar: &<Self::Field as Field>::Scalar) -> Self::Element {
+        RistrettoPoint::mul_base(scalar)
+    }
+
+    fn vartime_multiscalar_mul(
+        scalars: &[<Self::Field as Field>::Scalar],
+        elements: &[Self::Element],
+    ) -> Self::Element {
+        RistrettoPoint::vartime_multiscalar_mul(scalars, elements)
+    }
+
+    fn serialize(element: &Self::Element) -> Self::Serialization {
+        element.compress().to_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Element, GroupError> {
+        match CompressedRistretto::from_slice(buf.as_ref())
+            .map_err(|_| GroupError::MalformedElement)?
+            .decompress()
+        {
+            Some(point) => {
+                if point == Self::identity() {
+                    Err(GroupError::InvalidIdentityElement)
+                } else {
+                    Ok(point)
+                }
+            }
+            None => Err(GroupError::MalformedElement),
+        }
+    }
+}
+
+fn hash_to_array(inputs: &[&[u8]]) -> [u8; 64] {
+    let mut h = Sha512::new();
+    for i in inputs {
+        h.update(i);
+    }
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&h.finalize());
+    output
+}
+
+fn hash_to_scalar(inputs: &[&[u8]]) -> Scalar {
+    let output = hash_to_array(inputs);
+    Scalar::from_bytes_mod_order_wide(&output)
+}
+
+/// The marker bit that schnorrkel sets in the last byte of a serialized
+/// signature, to distinguish it from an Ed25519 signature.
+const SCHNORRKEL_MARKER: u8 = 0x80;
+
+/// Context string of the ciphersuite, following the naming of the
+/// ciphersuites in the [spec].
+///
+/// [spec]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-6.2-1
+const CONTEXT_STRING: &str = "FROST-SR25519-SHA512-v1";
+
+/// An implementation of the FROST(sr25519, SHA-512) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Sr25519Sha512;
+
+/// The ciphersuite-specific signing parameters which are fed into
+/// signing code to ensure correctly compliant signatures are computed.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SigningParameters {
+    /// The schnorrkel signing context, which identifies the role of the
+    /// signature in the larger protocol (e.g. `b"substrate"` for Substrate
+    /// extrinsics). The signature only verifies with `schnorrkel` under the
+    /// same context, i.e. with `signing_context(context).bytes(message)`.
+    ///
+    /// Defaults to the empty context.
+    pub context: Vec<u8>,
+}
+
+impl From<&[u8]> for SigningParameters {
+    fn from(context: &[u8]) -> Self {
+        SigningParameters {
+            context: context.to_vec(),
+        }
+    }
+}
+
+impl frost_core::SigningParameters for SigningParameters {
+    /// Encodes the parameters as the bytes of the signing context.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.context.clone()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(bytes.into())
+    }
+}
+
+/// The message target which the group's signature should commit to. Includes
+/// a message byte vector, and a set of ciphersuite-specific parameters.
+pub type SigningTarget = frost_core::SigningTarget<Sr25519Sha512>;
+
+impl Ciphersuite for Sr25519Sha512 {
+    const ID: &'static str = CONTEXT_STRING;
+
+    type Group = Sr25519Group;
+
+    type HashOutput = [u8; 64];
+
+    type SignatureSerialization = [u8; 64];
+
+    type SigningParameters = SigningParameters;
+
+    type VerificationMode = ();
+
+    /// H1 for FROST(sr25519, SHA-512)
+    fn H1(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"rho", m])
+    }
+
+    /// H2 for FROST(sr25519, SHA-512)
+    ///
+    /// This is not used to compute the challenge, which is derived from a
+    /// Merlin transcript instead (see [`Ciphersuite::challenge()`]).
+    fn H2(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"chal", m])
+    }
+
+    /// H3 for FROST(sr25519, SHA-512)
+    fn H3(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"nonce", m])
+    }
+
+    /// H4 for FROST(sr25519, SHA-512)
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"msg", m])
+    }
+
+    /// H5 for FROST(sr25519, SHA-512)
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"com", m])
+    }
+
+    /// HDKG for FROST(sr25519, SHA-512)
+    fn HDKG(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"dkg", m]))
+    }
+
+    /// HID for FROST(sr25519, SHA-512)
+    fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
+    }
+
+    /// Generates the challenge from the same Merlin transcript as
+    /// schnorrkel's `sign_simple()` and `verify_simple()`, with the signing
+    /// context taken from the [`SigningParameters`].
+    fn challenge(
+        R: &Element<S>,
+        verifying_key: &VerifyingKey,
+        sig_target: &SigningTarget,
+    ) -> Challenge<S> {
+        let mut t = Transcript::new(b"SigningContext");
+        t.append_message(b"", &sig_target.sig_params().context);
+        t.append_message(b"sign-bytes", sig_target.message());
+        t.append_message(b"proto-name", b"Schnorr-sig");
+        t.append_message(b"sign:pk", &verifying_key.serialize());
+        t.append_message(b"sign:R", &Sr25519Group::serialize(R));
+
+        let mut output = [0u8; 64];
+        t.challenge_bytes(b"sign:c", &mut output);
+        Challenge::from_scalar(Scalar::from_bytes_mod_order_wide(&output))
+    }
+
+    /// Serializes the signature as schnorrkel does, setting the most
+    /// significant bit of the last byte.
+    fn serialize_signature(signature: &Signature) -> Self::SignatureSerialization {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&Sr25519Group::serialize(signature.R()));
+        bytes[32..].copy_from_slice(&Sr25519ScalarField::serialize(signature.z()));
+        bytes[63] |= SCHNORRKEL_MARKER;
+        bytes
+    }
+
+    /// Deserializes a signature serialized by schnorrkel, which must have the
+    /// most significant bit of the last byte set.
+    fn deserialize_signature(bytes: Self::SignatureSerialization) -> Result<Signature, Error> {
+        if bytes[63] & SCHNORRKEL_MARKER == 0 {
+            return Err(Error::MalformedSignature);
+        }
+
+        let mut R_bytes = [0u8; 32];
+        R_bytes.copy_from_slice(&bytes[..32]);
+
+        let mut z_bytes = [0u8; 32];
+        z_bytes.copy_from_slice(&bytes[32..]);
+        z_bytes[31] &= !SCHNORRKEL_MARKER;
+
+        let R = Sr25519Group::deserialize(&R_bytes)?;
+        let z = Sr25519ScalarField::deserialize(&z_bytes)?;
+
+        Ok(Signature::new(R, z))
+    }
+}
+
+impl RandomizedCiphersuite for Sr25519Sha512 {
+    fn hash_randomizer(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[
+            CONTEXT_STRING.as_bytes(),
+            b"randomizer",
+            m,
+        ]))
+    }
+}
+
+type S = Sr25519Sha512;
+
+/// A FROST(sr25519, SHA-512) participant identifier.
+pub type Identifier = frost::Identifier<S>;
+
+/// FROST(sr25519, SHA-512) keys, key generation, key shares.
+pub mod keys {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    /// The identifier list to use when generating key shares.
+    pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, S>;
+
+    /// Allows all participants' keys to be generated using a central, trusted
+    /// dealer.
+    pub fn generate_with_dealer<RNG: RngCore + CryptoRng>(
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        mut rng: RNG,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, &mut rng)
+    }
+
+    /// Splits an existing key into FROST shares.
+    ///
+    /// This is identical to [`generate_with_dealer`] but receives an existing key
+    /// instead of generating a fresh one. This is useful in scenarios where
+    /// the key needs to be generated externally or must be derived from e.g. a
+    /// seed phrase.
+    pub fn split<R: RngCore + CryptoRng>(
+        secret: &SigningKey,
+        max_signers: u16,
+        min_signers: u16,
+        identifiers: IdentifierList,
+        rng: &mut R,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
+    }
+
+    /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
+    ///
+    /// This can be used if for some reason the original key must be restored; e.g.
+    /// if threshold signing is not required anymore.
+    ///
+    /// This is NOT required to sign with FROST; the whole point of FROST is being
+    /// able to generate signatures only using the shares, without having to
+    /// reconstruct the original key.
+    ///
+    /// The caller is responsible for providing at least `min_signers` shares;
+    /// if less than that is provided, a different key will be returned.
+    pub fn reconstruct(secret_shares: &[KeyPackage]) -> Result<SigningKey, Error> {
+        frost::keys::reconstruct(secret_shares)
+    }
+
+    /// Secret and public key material generated by a dealer performing
+    /// [`generate_with_dealer`].
+    ///
+    /// # Security
+    ///
+    /// To derive a FROST(sr25519, SHA-512) keypair, the receiver of the [`SecretShare`] *must* call
+    /// .into(), which under the hood also performs validation.
+    pub type SecretShare = frost::keys::SecretShare<S>;
+
+    /// A secret scalar value representing a signer's share of the group secret.
+    pub type SigningShare = frost::keys::SigningShare<S>;
+
+    /// A public group element that represents a single signer's public verification share.
+    pub type VerifyingShare = frost::keys::VerifyingShare<S>;
+
+    /// A FROST(sr25519, SHA-512) keypair, which can be generated either by a trusted dealer or using
+    /// a DKG.
+    ///
+    /// When using a central dealer, [`SecretShare`]s are distributed to
+    /// participants, who then perform verification, before deriving
+    /// [`KeyPackage`]s, which they store to later use during signing.
+    pub type KeyPackage = frost::keys::KeyPackage<S>;
+
+    /// Public data that contains all the signers' public keys as well as the
+    /// group public key.
+    ///
+    /// Used for verification purposes before publishing a signature.
+    pub type PublicKeyPackage = frost::keys::PublicKeyPackage<S>;
+
+    /// Contains the commitments to the coefficients for our secret polynomial _f_,
+    /// used to generate participants' key shares.
+    ///
+    /// [`VerifiableSecretSharingCommitment`] contains a set of commitments to the coefficients (which
+    /// themselves are scalars) for a secret polynomial f, where f is used to
+    /// generate each ith participant's key share f(i). Participants use this set of
+    /// commitments to perform verifiable secret sharing.
+    ///
+    /// Note that participants MUST be assured that they have the *same*
+    /// [`VerifiableSecretSharingCommitment`], either by performing pairwise comparison, or by using
+    /// some agreed-upon public location for publication, where each participant can
+    /// ensure that they received the correct (and same) value.
+    pub type VerifiableSecretSharingCommitment = frost::keys::VerifiableSecretSharingCommitment<S>;
+
+    /// A description of a FROST(sr25519, SHA-512) group: its threshold, its participants and
+    /// the commitment to the polynomial their shares were derived from.
+    pub type GroupDescriptor = frost::keys::group::GroupDescriptor<S>;
+
+    pub mod dkg;
+    pub mod repairable;
+}
+
+/// FROST(sr25519, SHA-512) Round 1 functionality and types.
+pub mod round1 {
+    use crate::keys::SigningShare;
+
+    use super::*;
+
+    /// Comprised of FROST(sr25519, SHA-512) hiding and binding nonces.
+    ///
+    /// Note that [`SigningNonces`] must be used *only once* for a signing
+    /// operation; re-using nonces will result in leakage of a signer's long-lived
+    /// signing key.
+    pub type SigningNonces = frost::round1::SigningNonces<S>;
+
+    /// Published by each participant in the first round of the signing protocol.
+    ///
+    /// This step can be batched if desired by the implementation. Each
+    /// SigningCommitment can be used for exactly *one* signature.
+    pub type SigningCommitments = frost::round1::SigningCommitments<S>;
+
+    /// A commitment to a signing nonce share.
+    pub type NonceCommitment = frost::round1::NonceCommitment<S>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Generates the signing nonces and commitments to be used in the signing
+    /// operation.
+    pub fn commit<RNG>(secret: &SigningShare, rng: &mut RNG) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit::<S, RNG>(secret, rng)
+    }
+}
+
+/// Generated by the coordinator of the signing operation and distributed to
+/// each signing party.
+pub type SigningPackage = frost::SigningPackage<S>;
+
+/// FROST(sr25519, SHA-512) Round 2 functionality and types, for signature share generation.
+pub mod round2 {
+    use super::*;
+
+    /// A FROST(sr25519, SHA-512) participant's signature share, which the Coordinator will aggregate with all other signer's
+    /// shares into the joint signature.
+    pub type SignatureShare = frost::round2::SignatureShare<S>;
+
+    /// Performed once by each participant selected for the signing operation.
+    ///
+    /// Receives the message to be signed and a set of signing commitments and a set
+    /// of randomizing commitments to be used in that signing operation, including
+    /// that for this participant.
+    ///
+    /// Assumes the participant has already determined which nonce corresponds with
+    /// the commitment that was assigned by the coordinator in the SigningPackage.
+    pub fn sign(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign(signing_package, signer_nonces, key_package)
+    }
+}
+
+/// FROST(sr25519, SHA-512) password-encrypted storage of secret material.
+#[cfg(feature = "keystore")]
+pub mod keystore {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    pub use frost::keystore::{
+        KdfParams, KEYSTORE_VERSION, MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST,
+    };
+
+    /// A FROST(sr25519, SHA-512) key package to be encrypted with a password, along with the
+    /// group's public key package and application metadata, if any.
+    pub type KeyPackageStore = frost::keystore::KeyPackageStore<S>;
+
+    /// Encrypt FROST(sr25519, SHA-512) signing nonces with a key derived from `password`.
+    ///
+    /// The nonces must still be used only once: the caller must delete the
+    /// encrypted nonces once they are used.
+    pub fn encrypt_signing_nonces<RNG: RngCore + CryptoRng>(
+        signing_nonces: &round1::SigningNonces,
+        password: &[u8],
+        params: &KdfParams,
+        rng: RNG,
+    ) -> Result<Vec<u8>, Error> {
+        frost::keystore::encrypt_signing_nonces(signing_nonces, password, params, rng)
+    }
+
+    /// Decrypt FROST(sr25519, SHA-512) signing nonces encrypted with [`encrypt_signing_nonces()`].
+    pub fn decrypt_signing_nonces(
+        bytes: &[u8],
+        password: &[u8],
+    ) -> Result<round1::SigningNonces, Error> {
+        frost::keystore::decrypt_signing_nonces(bytes, password)
+    }
+}
+
+/// A Schnorr signature on FROST(sr25519, SHA-512).
+pub type Signature = frost_core::Signature<S>;
+
+/// Verifies each FROST(sr25519, SHA-512) participant's signature share, and if all are valid,
+/// aggregates the shares into a signature to publish.
+///
+/// Resulting signature is compatible with verification of a plain Schnorr
+/// signature.
+///
+/// This operation is performed by a coordinator that can communicate with all
+/// the signing participants before publishing the final signature. The
+/// coordinator can be one of the participants or a semi-trusted third party
+/// (who is trusted to not perform denial of service attacks, but does not learn
+/// any secret information). Note that because the coordinator is trusted to
+/// report misbehaving parties in order to avoid publishing an invalid
+/// signature, if the coordinator themselves is a signer and misbehaves, they
+/// can avoid that step. However, at worst, this results in a denial of
+/// service attack due to publishing an invalid signature.
+pub fn aggregate(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate(signing_package, signature_shares, pubkeys)
+}
+
+/// A signing key for a Schnorr signature on FROST(sr25519, SHA-512).
+pub type SigningKey = frost_core::SigningKey<S>;
+
+/// A valid verifying key for Schnorr signatures on FROST(sr25519, SHA-512).
+pub type VerifyingKey = frost_core::VerifyingKey<S>;
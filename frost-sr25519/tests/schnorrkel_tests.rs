@@ -0,0 +1,115 @@
+use frost_sr25519::*;
+use rand::thread_rng;
+
+const CONTEXT: &[u8] = b"substrate";
+
+/// Check that `signature` is a valid sr25519 signature of the target with
+/// `verifying_key` according to the `schnorrkel` crate.
+fn verify_with_schnorrkel(
+    target: &SigningTarget,
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+) {
+    let public_key = schnorrkel::PublicKey::from_bytes(&verifying_key.serialize()).unwrap();
+    let signature = schnorrkel::Signature::from_bytes(&signature.serialize()).unwrap();
+    let transcript =
+        schnorrkel::signing_context(&target.sig_params().context).bytes(target.message());
+    public_key.verify(transcript, &signature).unwrap();
+}
+
+#[test]
+fn check_sign_with_dealer_interoperability() {
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Sr25519Sha512, _>(
+            thread_rng(),
+            SigningTarget::new(b"message", CONTEXT),
+        );
+
+    verify_with_schnorrkel(&target, &group_signature, &group_pubkey);
+}
+
+#[test]
+fn check_sign_with_dkg_interoperability() {
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Sr25519Sha512, _>(
+            thread_rng(),
+            SigningTarget::new(b"message", CONTEXT),
+        );
+
+    verify_with_schnorrkel(&target, &group_signature, &group_pubkey);
+}
+
+#[test]
+fn check_sign_with_empty_context_interoperability() {
+    let (target, group_signature, group_pubkey) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Sr25519Sha512, _>(
+            thread_rng(),
+            b"message".into(),
+        );
+
+    assert!(target.sig_params().context.is_empty());
+    verify_with_schnorrkel(&target, &group_signature, &group_pubkey);
+}
+
+#[test]
+fn check_randomized_sign_with_dealer_interoperability() {
+    let (msg, group_signature, randomized_group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<Sr25519Sha512, _>(
+            thread_rng(),
+        );
+
+    verify_with_schnorrkel(
+        &SigningTarget::from_message(msg),
+        &group_signature,
+        &randomized_group_pubkey,
+    );
+}
+
+#[test]
+fn check_single_signer_interoperability() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let target = SigningTarget::new(b"message", CONTEXT);
+    let signature = signing_key.sign(&mut rng, target.clone());
+
+    verify_with_schnorrkel(&target, &signature, &VerifyingKey::from(signing_key));
+}
+
+#[test]
+fn check_verify_schnorrkel_signature() {
+    let keypair = schnorrkel::Keypair::generate_with(thread_rng());
+    let signature = keypair.sign_simple(CONTEXT, b"message");
+
+    let verifying_key = VerifyingKey::deserialize(keypair.public.to_bytes()).unwrap();
+    let signature = Signature::deserialize(signature.to_bytes()).unwrap();
+
+    verifying_key
+        .verify(SigningTarget::new(b"message", CONTEXT), &signature)
+        .unwrap();
+    // The signing context is part of the challenge.
+    assert!(verifying_key
+        .verify(
+            SigningTarget::new(b"message", b"other".as_slice()),
+            &signature
+        )
+        .is_err());
+}
+
+#[test]
+fn check_deserialize_signature_requires_marker() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let mut bytes = signing_key
+        .sign(&mut rng, SigningTarget::new(b"message", CONTEXT))
+        .serialize();
+    assert_eq!(bytes[63] & 0x80, 0x80);
+
+    // An unmarked signature could be an Ed25519 signature, which schnorrkel
+    // refuses to deserialize.
+    bytes[63] &= 0x7f;
+    assert!(schnorrkel::Signature::from_bytes(&bytes).is_err());
+    assert_eq!(
+        Signature::deserialize(bytes),
+        Err(Error::MalformedSignature)
+    );
+}
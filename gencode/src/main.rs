@@ -257,6 +257,45 @@ fn main() -> ExitCode {
                 "<P>",
             ],
         ),
+        (
+            "frost-p384",
+            &[
+                "P384Sha384",
+                "P-384 curve",
+                "P384",
+                "FROST(P-384, SHA-384)",
+                "FROST-P384-SHA384-v1",
+                "p384_sha384",
+                "p384",
+                "<P>",
+            ],
+        ),
+        (
+            "frost-p521",
+            &[
+                "P521Sha512",
+                "P-521 curve",
+                "P521",
+                "FROST(P-521, SHA-512)",
+                "FROST-P521-SHA512-v1",
+                "p521_sha512",
+                "p521",
+                "<P>",
+            ],
+        ),
+        (
+            "frost-sr25519",
+            &[
+                "Sr25519Sha512",
+                "sr25519 group, compatible with schnorrkel,",
+                "Sr25519",
+                "FROST(sr25519, SHA-512)",
+                "FROST-SR25519-SHA512-v1",
+                "sr25519_sha512",
+                "sr25519",
+                "<S>",
+            ],
+        ),
         (
             "frost-ed25519",
             &[
@@ -283,6 +322,19 @@ fn main() -> ExitCode {
                 "<E>",
             ],
         ),
+        (
+            "frost-decaf448",
+            &[
+                "Decaf448Shake256",
+                "Decaf448 group",
+                "Decaf448",
+                "FROST(decaf448, SHAKE256)",
+                "FROST-DECAF448-SHAKE256-v1",
+                "decaf448_shake256",
+                "decaf448",
+                "<D>",
+            ],
+        ),
         (
             "frost-secp256k1",
             &[
@@ -309,6 +361,32 @@ fn main() -> ExitCode {
                 "<S>",
             ],
         ),
+        (
+            "frost-redpallas",
+            &[
+                "PallasBlake2b512",
+                "Pallas curve",
+                "Pallas",
+                "FROST(Pallas, BLAKE2b-512)",
+                "FROST(Pallas, BLAKE2b-512)",
+                "pallas_blake2b512",
+                "redpallas",
+                "<P>",
+            ],
+        ),
+        (
+            "frost-redjubjub",
+            &[
+                "JubjubBlake2b512",
+                "Jubjub curve",
+                "Jubjub",
+                "FROST(Jubjub, BLAKE2b-512)",
+                "FROST(Jubjub, BLAKE2b-512)",
+                "jubjub_blake2b512",
+                "redjubjub",
+                "<J>",
+            ],
+        ),
     ] {
         // Some test use "sample" values. To make these tests work for another ciphersuites,
         // these values must be replaced. To make it cleaner, the strings are